@@ -0,0 +1,157 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::BigUint;
+
+/// A part's answer, as produced by a [`Solver`](crate::Solver) and then
+/// checked, recorded, reported and submitted.
+///
+/// Integers are kept in `Int` whenever they fit, and `Big` only holds
+/// values above `i128::MAX`, so equal numbers always compare equal however
+/// they were computed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Big(BigUint),
+    Text(String),
+    /// The part has no answer, e.g. a last day with only one puzzle.
+    Unsolved,
+}
+
+impl Answer {
+    /// Reads an answer written out as text: an integer if it is one,
+    /// otherwise the text itself.
+    pub fn parse(text: &str) -> Self {
+        let digits = text.strip_prefix('-').unwrap_or(text);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Answer::Text(text.to_string());
+        }
+        match (text.parse(), digits.parse::<BigUint>()) {
+            (Ok(value), _) => Answer::Int(value),
+            (Err(_), Ok(big)) if digits.len() == text.len() => big.into(),
+            _ => Answer::Text(text.to_string()),
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        *self != Answer::Unsolved
+    }
+
+    /// Whether this is an integer, small or big.
+    pub fn is_integer(&self) -> bool {
+        matches!(self, Answer::Int(_) | Answer::Big(_))
+    }
+}
+
+macro_rules! from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        BigUint::from(value).into()
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        match value.to_u128().and_then(|value| i128::try_from(value).ok()) {
+            Some(value) => Answer::Int(value),
+            None => Answer::Big(value),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Integers are ordered by value; anything else only equals itself.
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Int(a), Answer::Int(b)) => Some(a.cmp(b)),
+            (Answer::Big(a), Answer::Big(b)) => Some(a.cmp(b)),
+            (Answer::Int(_), Answer::Big(_)) => Some(Ordering::Less),
+            (Answer::Big(_), Answer::Int(_)) => Some(Ordering::Greater),
+            (a, b) => (a == b).then_some(Ordering::Equal),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => value.fmt(f),
+            Answer::Big(value) => value.fmt(f),
+            Answer::Text(text) => f.pad(text),
+            Answer::Unsolved => f.pad("unsolved"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical() {
+        let wide = BigUint::from(u64::MAX) * 4;
+        assert_eq!(Answer::from(wide), Answer::Int(i128::from(u64::MAX) * 4));
+        let square = BigUint::from(u64::MAX) * u64::MAX;
+        let value = square.to_u128().unwrap();
+        assert_eq!(Answer::from(square.clone()), Answer::from(value));
+        assert_eq!(Answer::from(value), Answer::Big(square));
+        assert_eq!(Answer::from(BigUint::from(7u64)), Answer::from(7u8));
+        let huge = "340282366920938463463374607431768211456";
+        assert!(matches!(Answer::parse(huge), Answer::Big(_)));
+        assert_eq!(
+            Answer::from(u128::MAX).to_string(),
+            u128::MAX.to_string()
+        );
+        assert_eq!(Answer::parse(huge).to_string(), huge);
+
+        assert_eq!(Answer::parse("-12"), Answer::Int(-12));
+        assert_eq!(Answer::parse("+12"), Answer::from("+12"));
+        assert_eq!(
+            Answer::parse(&format!("-{huge}")),
+            Answer::from(format!("-{huge}"))
+        );
+        assert_eq!(Answer::parse("a b"), Answer::from("a b"));
+        assert_eq!(Answer::parse(""), Answer::from(""));
+        assert!(!Answer::Unsolved.is_solved());
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn order() {
+        let huge = Answer::from(u128::MAX);
+        assert!(Answer::Int(-3) < Answer::Int(2));
+        assert!(Answer::Int(i128::MAX) < huge);
+        assert!(huge > Answer::Int(0));
+        assert_eq!(Answer::from("b").partial_cmp(&Answer::from("a")), None);
+        assert_eq!(Answer::from("1").partial_cmp(&Answer::Int(1)), None);
+        assert_eq!(
+            Answer::Unsolved.partial_cmp(&Answer::Unsolved),
+            Some(Ordering::Equal)
+        );
+    }
+}
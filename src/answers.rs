@@ -0,0 +1,308 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{Answer, Error, Part, Result};
+
+/// File name of the recorded answers, kept in the input directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known-good answers for the real inputs, stored as a small TOML file:
+///
+/// ```toml
+/// [day01]
+/// part_one = 1123
+/// part_two = 6695
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<u8, BTreeMap<Part, Answer>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("PASS"),
+            Verdict::Fail { expected } => {
+                write!(f, "FAIL, expected {expected}")
+            }
+            Verdict::Unknown => f.write_str("UNKNOWN"),
+        }
+    }
+}
+
+impl Answers {
+    /// Loads the answers file; a missing file is treated as empty.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text, path),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(source) => Err(Error::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        fs::write(path, self.to_string()).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Parses answers text; `path` is only used in error messages.
+    pub fn parse(text: &str, path: &Path) -> Result<Self> {
+        let invalid = |line: usize, message: String| Error::Invalid {
+            path: PathBuf::from(path),
+            line: line + 1,
+            message,
+        };
+
+        let mut answers = Self::default();
+        let mut day = None;
+        for (n, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(section) = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+            {
+                let parsed = section
+                    .trim()
+                    .strip_prefix("day")
+                    .and_then(|d| d.parse().ok())
+                    .ok_or_else(|| {
+                        invalid(n, format!("bad section {line}"))
+                    })?;
+                day = Some(parsed);
+                continue;
+            }
+
+            let day = day.ok_or_else(|| {
+                invalid(n, "answer outside a `[dayNN]` section".into())
+            })?;
+            let (part, value) =
+                parse_entry(line).map_err(|message| invalid(n, message))?;
+            answers.set(day, part, value);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.days.get(&day)?.get(&part)
+    }
+
+    /// Records `answer`; an unsolved part has nothing to record.
+    pub fn set(&mut self, day: u8, part: Part, answer: impl Into<Answer>) {
+        let answer = answer.into();
+        if answer.is_solved() {
+            self.days.entry(day).or_default().insert(part, answer);
+        }
+    }
+
+    pub fn verify(&self, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (day, parts)) in self.days.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "[day{day:02}]")?;
+            for (part, answer) in parts {
+                writeln!(f, "{} = {}", part.key(), quote(answer))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the expected answers of an example input, stored beside it as
+/// `NN-<name>.expected` with the same `part_one = ...` lines as a day
+/// section of the answers file.
+pub(crate) fn parse_expected(
+    text: &str,
+    path: &Path,
+) -> Result<BTreeMap<Part, Answer>> {
+    let mut expected = BTreeMap::new();
+    for (n, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (part, value) =
+            parse_entry(line).map_err(|message| Error::Invalid {
+                path: PathBuf::from(path),
+                line: n + 1,
+                message,
+            })?;
+        expected.insert(part, value);
+    }
+    Ok(expected)
+}
+
+fn parse_entry(line: &str) -> Result<(Part, Answer), String> {
+    let (key, value) = line
+        .split_once('=')
+        .ok_or_else(|| "expected `key = value`".to_string())?;
+    let (key, value) = (key.trim(), value.trim());
+    let part =
+        Part::from_key(key).ok_or_else(|| format!("unknown key `{key}`"))?;
+    let value = unquote(value).ok_or_else(|| format!("bad value {value}"))?;
+    Ok((part, value))
+}
+
+// Integers are written bare, anything else as a basic TOML string. TOML
+// integers stop at the i64 range, so wider ones are quoted too and read
+// back as numbers, as is text that looks like one of them.
+fn quote(answer: &Answer) -> String {
+    match answer {
+        Answer::Text(text) => quote_text(text),
+        Answer::Int(value) if i64::try_from(*value).is_ok() => {
+            value.to_string()
+        }
+        answer => quote_text(&answer.to_string()),
+    }
+}
+
+fn unquote(value: &str) -> Option<Answer> {
+    if !value.starts_with('"') {
+        let answer = Answer::parse(value);
+        return answer.is_integer().then_some(answer);
+    }
+    let text = unquote_text(value)?;
+    match Answer::parse(&text) {
+        Answer::Int(value) if i64::try_from(value).is_ok() => {
+            Some(Answer::Text(text))
+        }
+        answer => Some(answer),
+    }
+}
+
+/// `text` in double quotes, with backslashes, quotes and newlines escaped
+/// so that it stays on one line.
+pub(crate) fn quote_text(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+/// The text [`quote_text`] wrote, or None if `value` is not quoted that
+/// way.
+pub(crate) fn unquote_text(value: &str) -> Option<String> {
+    let inner = value.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                c @ ('\\' | '"') => result.push(c),
+                'n' => result.push('\n'),
+                _ => return None,
+            },
+            '"' => return None,
+            c => result.push(c),
+        }
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+        answers.set(2, Part::Two, 34421651192u64);
+        answers.set(1, Part::One, 1123);
+        answers.set(1, Part::Two, "say \"hi\"");
+        answers.set(2, Part::One, "7");
+        answers.set(3, Part::One, Answer::Unsolved);
+        answers.set(4, Part::One, i128::from(i64::MIN) - 1);
+        answers.set(4, Part::Two, u128::MAX);
+
+        let text = answers.to_string();
+        assert_eq!(
+            text,
+            "[day01]\npart_one = 1123\npart_two = \"say \\\"hi\\\"\"\n\n\
+             [day02]\npart_one = \"7\"\npart_two = 34421651192\n\n\
+             [day04]\npart_one = \"-9223372036854775809\"\n\
+             part_two = \"340282366920938463463374607431768211455\"\n"
+        );
+        assert_eq!(
+            Answers::parse(&text, Path::new("a.toml")).unwrap(),
+            answers
+        );
+    }
+
+    #[test]
+    fn verify() {
+        let mut answers = Answers::default();
+        answers.set(1, Part::One, 3);
+        let verify = |part, answer| answers.verify(1, part, &answer);
+        assert_eq!(verify(Part::One, Answer::Int(3)), Verdict::Pass);
+        assert_eq!(
+            verify(Part::One, Answer::Int(4)),
+            Verdict::Fail {
+                expected: Answer::Int(3)
+            }
+        );
+        // A number is not the same answer as text with its digits
+        assert!(matches!(
+            verify(Part::One, "3".into()),
+            Verdict::Fail { .. }
+        ));
+        assert_eq!(verify(Part::Two, Answer::Int(6)), Verdict::Unknown);
+    }
+
+    #[test]
+    fn expected() {
+        let path = Path::new("11-example2.expected");
+        let expected = parse_expected("part_two = 2\n", path).unwrap();
+        assert_eq!(expected.get(&Part::One), None);
+        assert_eq!(expected.get(&Part::Two), Some(&Answer::Int(2)));
+
+        let err = parse_expected("part_two 2\n", path).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "11-example2.expected:1: expected `key = value`"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let err = Answers::parse("[day01]\npart_three = 1\n", Path::new("a"))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "a:2: unknown key `part_three`");
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::{Error, Result, Solver};
+
+/// Where `aoc bench` keeps the baseline of `year`; baselines are keyed by
+/// day, so each year has its own.
+pub fn baseline_file(year: u16) -> PathBuf {
+    PathBuf::from(format!("target/bench-baseline-{year}.txt"))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// Untimed runs before sampling starts.
+    pub warmup: usize,
+    /// Timed runs per stage.
+    pub runs: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        if samples.is_empty() {
+            return Self {
+                min: Duration::ZERO,
+                median: Duration::ZERO,
+                mean: Duration::ZERO,
+                stddev: Duration::ZERO,
+            };
+        }
+
+        samples.sort();
+        let n = samples.len();
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+
+        let secs: Vec<f64> =
+            samples.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance =
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Self {
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "min {:>9.2?}  median {:>9.2?}", self.min, self.median)?;
+        write!(
+            f,
+            "  mean {:>9.2?}  stddev {:>9.2?}",
+            self.mean, self.stddev
+        )
+    }
+}
+
+/// Timings for one day, with parsing measured apart from solving.
+#[derive(Debug, Clone, Copy)]
+pub struct DayBench {
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+impl DayBench {
+    pub fn stages(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part_one", &self.part_one),
+            ("part_two", &self.part_two),
+        ]
+    }
+}
+
+pub(crate) fn run<S: Solver>(
+    input: &str,
+    config: &BenchConfig,
+) -> Result<DayBench> {
+    let parse = sample(config, || S::parse(black_box(input)))?;
+    let parsed = S::parse(input)?;
+    let part_one = sample(config, || S::part_one(black_box(&parsed)))?;
+    let part_two = sample(config, || S::part_two(black_box(&parsed)))?;
+    Ok(DayBench {
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+fn sample<T>(
+    config: &BenchConfig,
+    mut f: impl FnMut() -> Result<T>,
+) -> Result<Stats> {
+    for _ in 0..config.warmup {
+        black_box(f()?);
+    }
+
+    let mut samples = Vec::with_capacity(config.runs);
+    for _ in 0..config.runs {
+        let start = Instant::now();
+        black_box(f()?);
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::from_samples(&mut samples))
+}
+
+/// Median timings saved from an earlier run, keyed by day and stage.
+#[derive(Debug, Clone, Default)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, String), Duration>,
+}
+
+impl Baseline {
+    /// Loads a saved baseline; a missing file gives an empty one.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Ok(Self::default());
+            }
+            Err(source) => {
+                let path = path.to_path_buf();
+                return Err(Error::Io { path, source });
+            }
+        };
+
+        let mut baseline = Self::default();
+        for (n, line) in text.lines().enumerate() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let entry = match fields[..] {
+                [] => continue,
+                [day, stage, nanos] => day
+                    .parse()
+                    .ok()
+                    .zip(nanos.parse().ok())
+                    .map(|(day, nanos)| (day, stage, nanos)),
+                _ => None,
+            };
+            let Some((day, stage, nanos)) = entry else {
+                return Err(Error::Invalid {
+                    path: PathBuf::from(path),
+                    line: n + 1,
+                    message: "expected `day stage nanoseconds`".into(),
+                });
+            };
+            baseline.set(day, stage, Duration::from_nanos(nanos));
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut text = String::new();
+        for ((day, stage), median) in &self.medians {
+            text += &format!("{day} {stage} {}\n", median.as_nanos());
+        }
+        fs::write(path, text).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn get(&self, day: u8, stage: &str) -> Option<Duration> {
+        self.medians.get(&(day, stage.to_string())).copied()
+    }
+
+    pub fn set(&mut self, day: u8, stage: &str, median: Duration) {
+        self.medians.insert((day, stage.to_string()), median);
+    }
+
+    pub fn record(&mut self, day: u8, bench: &DayBench) {
+        for (stage, stats) in bench.stages() {
+            self.set(day, stage, stats.median);
+        }
+    }
+
+    /// Percentage change of `median` relative to the saved value.
+    pub fn delta(
+        &self,
+        day: u8,
+        stage: &str,
+        median: Duration,
+    ) -> Option<f64> {
+        let base = self.get(day, stage)?.as_secs_f64();
+        (base > 0.0).then(|| (median.as_secs_f64() - base) / base * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let mut samples: Vec<Duration> =
+            [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        let stddev = stats.stddev.as_secs_f64() * 1000.0;
+        assert!((stddev - 1.25f64.sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn baseline_delta() {
+        let mut baseline = Baseline::default();
+        baseline.set(1, "parse", Duration::from_millis(10));
+        let delta = baseline.delta(1, "parse", Duration::from_millis(12));
+        assert!((delta.unwrap() - 20.0).abs() < 1e-9);
+        assert_eq!(
+            baseline.delta(2, "parse", Duration::from_millis(1)),
+            None
+        );
+    }
+}
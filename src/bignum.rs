@@ -0,0 +1,392 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Mul, MulAssign};
+use std::str::FromStr;
+
+/// An unsigned integer of any size, for answers that may not fit a `u64`.
+///
+/// Values that fit are kept in a `u64` and worked on with checked
+/// arithmetic; only a result that overflows moves to 32-bit limbs. Small
+/// counts cost about what a plain `u64` does.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BigUint(Repr);
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Repr {
+    Small(u64),
+    // Little-endian limbs of a value above `u64::MAX`, with no leading
+    // zero limb, so every value has one representation
+    Large(Vec<u32>),
+}
+
+/// A string that is not a decimal number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigUintError;
+
+impl BigUint {
+    pub const fn zero() -> Self {
+        Self(Repr::Small(0))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == Repr::Small(0)
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.0 {
+            Repr::Small(value) => Some(value),
+            Repr::Large(_) => None,
+        }
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        match &self.0 {
+            Repr::Small(value) => Some(u128::from(*value)),
+            Repr::Large(limbs) if limbs.len() <= 4 => {
+                let limbs = limbs.iter().rev();
+                Some(
+                    limbs.fold(0, |value, &limb| {
+                        value << 32 | u128::from(limb)
+                    }),
+                )
+            }
+            Repr::Large(_) => None,
+        }
+    }
+
+    /// `self - other`, or None if that would be negative.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if let (Repr::Small(a), Repr::Small(b)) = (&self.0, &other.0) {
+            return a.checked_sub(*b).map(Self::from);
+        }
+        if self < other {
+            return None;
+        }
+        let (a, b) = (self.limbs(), other.limbs());
+        let mut limbs = Vec::with_capacity(a.len());
+        let mut borrow = 0;
+        for (i, &limb) in a.iter().enumerate() {
+            let take = u64::from(b.get(i).copied().unwrap_or(0)) + borrow;
+            let (value, under) = u64::from(limb).overflowing_sub(take);
+            limbs.push(value as u32);
+            borrow = u64::from(under);
+        }
+        Some(Self::from_limbs(limbs))
+    }
+
+    /// The little-endian 32-bit limbs, at least one.
+    pub(crate) fn limbs(&self) -> Vec<u32> {
+        match &self.0 {
+            Repr::Small(value) if *value > u64::from(u32::MAX) => {
+                vec![*value as u32, (*value >> 32) as u32]
+            }
+            Repr::Small(value) => vec![*value as u32],
+            Repr::Large(limbs) => limbs.clone(),
+        }
+    }
+
+    pub(crate) fn from_limbs(mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        match limbs[..] {
+            [] => Self::zero(),
+            [low] => Self::from(low),
+            [low, high] => Self::from(u64::from(high) << 32 | u64::from(low)),
+            _ => Self(Repr::Large(limbs)),
+        }
+    }
+
+    // Divides in place by `divisor`, returning the remainder
+    fn div_small(limbs: &mut Vec<u32>, divisor: u32) -> u32 {
+        let mut rest = 0u64;
+        for limb in limbs.iter_mut().rev() {
+            let value = rest << 32 | u64::from(*limb);
+            *limb = (value / u64::from(divisor)) as u32;
+            rest = value % u64::from(divisor);
+        }
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        rest as u32
+    }
+}
+
+impl Default for BigUint {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        Self(Repr::Small(value))
+    }
+}
+
+impl From<u32> for BigUint {
+    fn from(value: u32) -> Self {
+        Self::from(u64::from(value))
+    }
+}
+
+impl From<usize> for BigUint {
+    fn from(value: usize) -> Self {
+        Self::from(value as u64)
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(value: u128) -> Self {
+        let limbs = (0..4).map(|i| (value >> (32 * i)) as u32).collect();
+        Self::from_limbs(limbs)
+    }
+}
+
+impl PartialEq<u64> for BigUint {
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == Repr::Small(*other)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (Repr::Small(a), Repr::Small(b)) => a.cmp(b),
+            (Repr::Small(_), Repr::Large(_)) => Ordering::Less,
+            (Repr::Large(_), Repr::Small(_)) => Ordering::Greater,
+            (Repr::Large(a), Repr::Large(b)) => {
+                let high = a.iter().rev().cmp(b.iter().rev());
+                a.len().cmp(&b.len()).then(high)
+            }
+        }
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialOrd<u64> for BigUint {
+    fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
+        Some(self.cmp(&Self::from(*other)))
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        if let (Repr::Small(a), Repr::Small(b)) = (&self.0, &other.0) {
+            if let Some(sum) = a.checked_add(*b) {
+                return BigUint::from(sum);
+            }
+        }
+        let (a, b) = (self.limbs(), other.limbs());
+        let mut limbs = Vec::with_capacity(a.len().max(b.len()) + 1);
+        let mut carry = 0;
+        for i in 0..a.len().max(b.len()) {
+            let sum = u64::from(a.get(i).copied().unwrap_or(0))
+                + u64::from(b.get(i).copied().unwrap_or(0))
+                + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        limbs.push(carry as u32);
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        if let (Repr::Small(a), Repr::Small(b)) = (&self.0, &other.0) {
+            return match a.checked_mul(*b) {
+                Some(product) => BigUint::from(product),
+                None => BigUint::from(u128::from(*a) * u128::from(*b)),
+            };
+        }
+        let (a, b) = (self.limbs(), other.limbs());
+        let mut limbs = vec![0u32; a.len() + b.len()];
+        for (i, &x) in a.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &y) in b.iter().enumerate() {
+                let value = u64::from(x) * u64::from(y)
+                    + u64::from(limbs[i + j])
+                    + carry;
+                limbs[i + j] = value as u32;
+                carry = value >> 32;
+            }
+            limbs[i + b.len()] = carry as u32;
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+// The by-value and mixed forms, all in terms of the two above
+macro_rules! forward {
+    ($op:ident $method:ident, $assign:ident $assign_method:ident) => {
+        impl $op for BigUint {
+            type Output = BigUint;
+
+            fn $method(self, other: BigUint) -> BigUint {
+                (&self).$method(&other)
+            }
+        }
+
+        impl $op<u64> for BigUint {
+            type Output = BigUint;
+
+            fn $method(self, other: u64) -> BigUint {
+                (&self).$method(&BigUint::from(other))
+            }
+        }
+
+        impl $assign<&BigUint> for BigUint {
+            fn $assign_method(&mut self, other: &BigUint) {
+                *self = (&*self).$method(other);
+            }
+        }
+
+        impl $assign for BigUint {
+            fn $assign_method(&mut self, other: BigUint) {
+                *self = (&*self).$method(&other);
+            }
+        }
+
+        impl $assign<u64> for BigUint {
+            fn $assign_method(&mut self, other: u64) {
+                *self = (&*self).$method(&BigUint::from(other));
+            }
+        }
+    };
+}
+
+forward!(Add add, AddAssign add_assign);
+forward!(Mul mul, MulAssign mul_assign);
+
+impl Sum for BigUint {
+    fn sum<I: Iterator<Item = BigUint>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |sum, value| sum + value)
+    }
+}
+
+impl<'a> Sum<&'a BigUint> for BigUint {
+    fn sum<I: Iterator<Item = &'a BigUint>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |sum, value| &sum + value)
+    }
+}
+
+impl Product for BigUint {
+    fn product<I: Iterator<Item = BigUint>>(iter: I) -> Self {
+        iter.fold(Self::from(1u64), |product, value| product * value)
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut limbs = match &self.0 {
+            Repr::Small(value) => return fmt::Display::fmt(value, f),
+            Repr::Large(limbs) => limbs.clone(),
+        };
+        // Nine decimal digits at a time, least significant first
+        let mut chunks = Vec::new();
+        while !limbs.is_empty() {
+            chunks.push(Self::div_small(&mut limbs, 1_000_000_000));
+        }
+        let mut digits = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:09}"));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl fmt::Debug for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseBigUintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigUintError);
+        }
+        let mut value = Self::zero();
+        for chunk in s.as_bytes().chunks(9) {
+            let chunk = std::str::from_utf8(chunk).unwrap();
+            value *= 10u64.pow(chunk.len() as u32);
+            value += chunk.parse::<u64>().unwrap();
+        }
+        Ok(value)
+    }
+}
+
+impl fmt::Display for ParseBigUintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid digit found in string")
+    }
+}
+
+impl std::error::Error for ParseBigUintError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+
+    fn big(text: &str) -> BigUint {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn display_and_parse() {
+        let two_64 = BigUint::from(u64::MAX) + 1;
+        assert_eq!(two_64.to_string(), "18446744073709551616");
+        assert_eq!(two_64.to_u64(), None);
+        assert_eq!((two_64.clone() * two_64.clone()).to_u128(), None);
+        assert_eq!(format!("{:>22}", two_64), "  18446744073709551616");
+        assert_eq!(
+            (1..=30u64).map(BigUint::from).product::<BigUint>(),
+            big("265252859812191058636308480000000")
+        );
+        let two_100 = (0..100).fold(BigUint::from(1u64), |n, _| n * 2);
+        assert_eq!(two_100.to_string(), "1267650600228229401496703205376");
+        assert_eq!(big("000"), 0);
+        assert_eq!(big("4000000000000000000"), 4_000_000_000_000_000_000);
+        assert!("12a".parse::<BigUint>().is_err());
+        assert!("".parse::<BigUint>().is_err());
+    }
+
+    #[test]
+    fn matches_u128() {
+        let mut rng = Rng::new(24);
+        for _ in 0..2000 {
+            let (a, b) = (rng.next_u64(), rng.next_u64() >> rng.below(64));
+            let (x, y) = (BigUint::from(a), BigUint::from(b));
+            let (a, b) = (u128::from(a), u128::from(b));
+            assert_eq!(&x + &y, BigUint::from(a + b));
+            assert_eq!((&x * &y).to_u128(), Some(a * b));
+            assert_eq!((&x * &y).to_string(), (a * b).to_string());
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+
+            // Past 128 bits, checked against (a b + a) - a b = a
+            let wide = &(&x * &y) * &(&x + &y);
+            let sum = &wide + &x;
+            assert_eq!(sum.checked_sub(&wide), Some(x.clone()));
+            assert_eq!(sum.checked_sub(&x), Some(wide.clone()));
+            assert_eq!(big(&sum.to_string()), sum);
+            assert!(sum >= wide);
+            if sum != x {
+                assert_eq!(x.checked_sub(&sum), None);
+            }
+        }
+    }
+}
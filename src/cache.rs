@@ -0,0 +1,287 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{Error, Result};
+
+/// File name of the fetch records, kept in the input directory.
+pub const MANIFEST_FILE: &str = "manifest.txt";
+
+// Phrases the site puts in place of an input when a request is refused
+const ERROR_PAGES: [&str; 2] = [
+    "Puzzle inputs differ by user",
+    "Please don't repeatedly request this",
+];
+
+/// What was stored when an input was fetched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fetched {
+    pub checksum: u64,
+    pub len: usize,
+    pub time: SystemTime,
+}
+
+/// Why an input file cannot be trusted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    Empty,
+    /// An error page was saved in place of the input.
+    Html,
+    /// Shorter than when it was fetched.
+    Truncated {
+        expected: usize,
+        actual: usize,
+    },
+    /// Same length or longer, but not what was fetched.
+    Changed,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::Empty => f.write_str("input is empty"),
+            Problem::Html => f.write_str("input is an HTML page"),
+            Problem::Truncated { expected, actual } => {
+                write!(f, "input is truncated, {actual} of {expected} bytes")
+            }
+            Problem::Changed => {
+                f.write_str("input changed since it was fetched")
+            }
+        }
+    }
+}
+
+/// State of a real input as reported by `aoc status`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputStatus {
+    Missing,
+    /// Present, but not fetched by `aoc fetch` so nothing to check against.
+    Unrecorded,
+    Fetched(Fetched),
+    Suspicious(Problem),
+}
+
+/// Checksums and fetch times of downloaded inputs, one per line as
+/// `day name checksum bytes unix-time`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    entries: BTreeMap<(u8, String), Fetched>,
+}
+
+impl Manifest {
+    /// Loads the manifest; a missing file gives an empty one.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text, path),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(source) => Err(Error::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        fs::write(path, self.to_string()).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn parse(text: &str, path: &Path) -> Result<Self> {
+        let mut manifest = Self::default();
+        for (n, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let Some((day, name, fetched)) = parse_entry(line) else {
+                return Err(Error::Invalid {
+                    path: PathBuf::from(path),
+                    line: n + 1,
+                    message: "expected `day name checksum bytes time`".into(),
+                });
+            };
+            manifest.set(day, name, fetched);
+        }
+        Ok(manifest)
+    }
+
+    pub fn get(&self, day: u8, name: &str) -> Option<&Fetched> {
+        self.entries.get(&(day, name.to_string()))
+    }
+
+    pub fn set(&mut self, day: u8, name: &str, fetched: Fetched) {
+        self.entries.insert((day, name.to_string()), fetched);
+    }
+}
+
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ((day, name), fetched) in &self.entries {
+            let time = fetched
+                .time
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            writeln!(
+                f,
+                "{day:02} {name} {:016x} {} {time}",
+                fetched.checksum, fetched.len
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_entry(line: &str) -> Option<(u8, &str, Fetched)> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let [day, name, checksum, len, time] = fields[..] else {
+        return None;
+    };
+    let fetched = Fetched {
+        checksum: u64::from_str_radix(checksum, 16).ok()?,
+        len: len.parse().ok()?,
+        time: UNIX_EPOCH + Duration::from_secs(time.parse().ok()?),
+    };
+    Some((day.parse().ok()?, name, fetched))
+}
+
+/// 64-bit FNV-1a hash of `text`.
+pub fn checksum(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Converts line endings to `\n` and ends non-empty text with exactly one.
+pub fn normalise(text: &str) -> String {
+    let mut result = text.replace("\r\n", "\n").replace('\r', "\n");
+    let len = result.trim_end_matches('\n').len();
+    result.truncate(len);
+    if !result.is_empty() {
+        result.push('\n');
+    }
+    result
+}
+
+/// Checks normalised input `text` against what was recorded when it was
+/// fetched, if it was.
+pub fn inspect(text: &str, fetched: Option<&Fetched>) -> Result<(), Problem> {
+    if text.trim().is_empty() {
+        return Err(Problem::Empty);
+    }
+    let head: String = text.trim_start().chars().take(9).collect();
+    let head = head.to_ascii_lowercase();
+    if head.starts_with("<!doctype")
+        || head.starts_with("<html")
+        || ERROR_PAGES.iter().any(|page| text.contains(page))
+    {
+        return Err(Problem::Html);
+    }
+    match fetched {
+        Some(fetched) if text.len() < fetched.len => {
+            Err(Problem::Truncated {
+                expected: fetched.len,
+                actual: text.len(),
+            })
+        }
+        Some(fetched) if checksum(text) != fetched.checksum => {
+            Err(Problem::Changed)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Formats `time` as `2025-12-01 05:00 UTC`.
+pub fn format_time(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+
+    // Civil date from days since 1970-01-01, after Howard Hinnant
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year}-{month:02}-{day:02} {:02}:{:02} UTC",
+        secs / 3600,
+        secs % 3600 / 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalise_line_endings() {
+        assert_eq!(normalise("a\r\nb\rc\n\n\n"), "a\nb\nc\n");
+        assert_eq!(normalise("a"), "a\n");
+        assert_eq!(normalise("\n\n"), "");
+    }
+
+    #[test]
+    fn problems() {
+        let text = "1 2\n3 4\n";
+        let fetched = Fetched {
+            checksum: checksum(text),
+            len: text.len(),
+            time: UNIX_EPOCH,
+        };
+        assert_eq!(inspect(text, Some(&fetched)), Ok(()));
+        assert_eq!(inspect("", None), Err(Problem::Empty));
+        assert_eq!(
+            inspect("<!DOCTYPE html>\n<html>", None),
+            Err(Problem::Html)
+        );
+        assert_eq!(
+            inspect("1 2\n", Some(&fetched)),
+            Err(Problem::Truncated {
+                expected: 8,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            inspect("1 2\n3 5\n", Some(&fetched)),
+            Err(Problem::Changed)
+        );
+    }
+
+    #[test]
+    fn manifest_round_trip() {
+        let mut manifest = Manifest::default();
+        let time = UNIX_EPOCH + Duration::from_secs(1_764_565_200);
+        manifest.set(
+            1,
+            "input",
+            Fetched {
+                checksum: 0xabc,
+                len: 12,
+                time,
+            },
+        );
+        let text = manifest.to_string();
+        assert_eq!(text, "01 input 0000000000000abc 12 1764565200\n");
+        assert_eq!(
+            Manifest::parse(&text, Path::new(MANIFEST_FILE)).unwrap(),
+            manifest
+        );
+        assert_eq!(format_time(time), "2025-12-01 05:00 UTC");
+    }
+}
@@ -0,0 +1,139 @@
+use std::cell::RefCell;
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use crate::{Error, Result};
+
+// How long a timed-out solver gets to notice its token before it is left
+// running in the background
+const GRACE: Duration = Duration::from_millis(100);
+
+thread_local! {
+    static CURRENT: RefCell<Option<Cancel>> = const { RefCell::new(None) };
+}
+
+/// Cooperative cancellation token shared between a runner and a solver.
+///
+/// Solvers do not take the token as an argument; long searches instead call
+/// [`check_cancelled`], which looks at the token installed for the current
+/// thread by [`Cancel::run`].
+#[derive(Debug, Clone, Default)]
+pub struct Cancel {
+    flag: Arc<AtomicBool>,
+}
+
+impl Cancel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::Relaxed)
+    }
+
+    /// Runs `f` with this token installed for the current thread. The
+    /// previous token is put back afterwards, even if `f` panics.
+    pub fn run<T>(&self, f: impl FnOnce() -> T) -> T {
+        let previous = CURRENT.with(|c| c.replace(Some(self.clone())));
+        let _restore = Restore(previous);
+        f()
+    }
+}
+
+// Puts a thread's earlier token back when dropped
+struct Restore(Option<Cancel>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CURRENT.with(|c| c.replace(self.0.take()));
+    }
+}
+
+/// Fails with [`Error::Cancelled`] once the current thread's token has been
+/// cancelled. Without a token this never fails.
+pub fn check_cancelled() -> Result<()> {
+    let cancelled = CURRENT
+        .with(|c| c.borrow().as_ref().is_some_and(Cancel::is_cancelled));
+    if cancelled {
+        Err(Error::Cancelled)
+    } else {
+        Ok(())
+    }
+}
+
+/// Runs `f` on its own thread, cancelling it after `timeout`.
+///
+/// A solver that checks its token stops shortly after; one that does not
+/// is left to finish in the background, and either way the caller gets
+/// [`Error::Cancelled`] without waiting for it.
+pub fn run_with_timeout<T, F>(timeout: Duration, f: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    let token = Cancel::new();
+    let (sender, receiver) = mpsc::channel();
+    let worker = token.clone();
+    let handle = thread::spawn(move || {
+        // The receiver is gone if we already gave up on this result
+        let _ = sender.send(worker.run(f));
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            token.cancel();
+            let _ = receiver.recv_timeout(GRACE);
+            Err(Error::Cancelled)
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            // The solver panicked, so pass that on as if run directly
+            match handle.join() {
+                Err(payload) => panic::resume_unwind(payload),
+                Ok(()) => unreachable!("the worker always sends a result"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spin() -> Result<u64> {
+        loop {
+            check_cancelled()?;
+            thread::yield_now();
+        }
+    }
+
+    #[test]
+    fn token_scoped_to_run() {
+        let token = Cancel::new();
+        token.cancel();
+        assert!(check_cancelled().is_ok());
+        assert!(matches!(token.run(check_cancelled), Err(Error::Cancelled)));
+        assert!(check_cancelled().is_ok());
+
+        // A panicking run still removes its token
+        let caught = panic::catch_unwind(|| token.run(|| panic!("boom")));
+        assert!(caught.is_err());
+        assert!(check_cancelled().is_ok());
+    }
+
+    #[test]
+    fn timeout() {
+        let fast = run_with_timeout(Duration::from_secs(10), || Ok(42));
+        assert_eq!(fast.unwrap(), 42);
+
+        let slow = run_with_timeout(Duration::from_millis(10), spin);
+        assert!(matches!(slow, Err(Error::Cancelled)));
+    }
+}
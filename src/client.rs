@@ -0,0 +1,447 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::{Error, Part, Result};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const SESSION_FILE: &str = ".aoc-session";
+const USER_AGENT: &str = "aoc-2025-in-rust (std client)";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Server verdict on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Submitted too soon after the last attempt; `wait` is how long the
+    /// server asked for, when it said.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part was solved before, or is not unlocked yet.
+    AlreadySolved,
+}
+
+impl Submission {
+    /// Reads the verdict from the article in an answer response page.
+    pub fn classify(html: &str) -> Option<Self> {
+        if html.contains("That's the right answer") {
+            Some(Submission::Correct)
+        } else if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                Some(Submission::TooHigh)
+            } else if html.contains("your answer is too low") {
+                Some(Submission::TooLow)
+            } else {
+                Some(Submission::Wrong)
+            }
+        } else if html.contains("You gave an answer too recently") {
+            let wait = html
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .and_then(|(wait, _)| parse_wait(wait));
+            Some(Submission::RateLimited { wait })
+        } else if html
+            .contains("You don't seem to be solving the right level")
+        {
+            Some(Submission::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            Submission::TooHigh | Submission::TooLow | Submission::Wrong
+        )
+    }
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Submission::Correct => f.write_str("correct"),
+            Submission::TooHigh => f.write_str("wrong, too high"),
+            Submission::TooLow => f.write_str("wrong, too low"),
+            Submission::Wrong => f.write_str("wrong"),
+            Submission::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            Submission::RateLimited { wait: None } => {
+                f.write_str("rate limited")
+            }
+            Submission::AlreadySolved => f.write_str("already solved"),
+        }
+    }
+}
+
+// Parses a wait such as `1m 5s` or `35s`
+fn parse_wait(text: &str) -> Option<Duration> {
+    let mut secs = 0;
+    for token in text.split_whitespace() {
+        let unit = token.find(|c: char| !c.is_ascii_digit())?;
+        let value: u64 = token[..unit].parse().ok()?;
+        secs += match &token[unit..] {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Finds the session cookie in `$AOC_SESSION`, then `.aoc-session`, then
+/// `~/.aoc-session`.
+pub fn session() -> Result<String> {
+    if let Some(session) =
+        env::var(SESSION_VAR).ok().filter(|s| !s.is_empty())
+    {
+        return Ok(session);
+    }
+
+    let mut candidates = vec![PathBuf::from(SESSION_FILE)];
+    if let Some(home) = env::var_os("HOME") {
+        candidates.push(PathBuf::from(home).join(SESSION_FILE));
+    }
+    for path in candidates {
+        if let Ok(text) = fs::read_to_string(&path) {
+            return Ok(text.trim().to_string());
+        }
+    }
+    Err(Error::Client(format!(
+        "no session token, set {SESSION_VAR} or create {SESSION_FILE}"
+    )))
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+/// Talks to the Advent of Code site, or to whatever `base_url` points at.
+///
+/// `http://` URLs are served over a plain [`TcpStream`]; anything else is
+/// handed to `curl`, since std has no TLS.
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+    year: u16,
+}
+
+impl Client {
+    /// A client for the puzzles of `year`, usually [`Inputs::year`].
+    ///
+    /// [`Inputs::year`]: crate::Inputs::year
+    pub fn new(base_url: &str, session: &str, year: u16) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            year,
+        }
+    }
+
+    /// Uses `$AOC_BASE_URL` (or the real site) and [`session`].
+    pub fn from_env(year: u16) -> Result<Self> {
+        let base_url = env::var(BASE_URL_VAR)
+            .ok()
+            .filter(|url| !url.is_empty())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        Ok(Self::new(&base_url, &session()?, year))
+    }
+
+    pub fn fetch_input(&self, day: u8) -> Result<String> {
+        let url = format!("{}/{}/day/{day}/input", self.base_url, self.year);
+        let response = self.request(&url, None)?;
+        let body = &response.body;
+        let message = if body.contains("Puzzle inputs differ by user") {
+            "invalid session token".to_string()
+        } else if body.contains("Please don't repeatedly request this") {
+            "rate limited or puzzle not yet available".to_string()
+        } else if response.status != 200 {
+            format!("HTTP {}", response.status)
+        } else {
+            return Ok(response.body);
+        };
+        Err(Error::Client(format!("{url}: {message}")))
+    }
+
+    pub fn submit(
+        &self,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Submission> {
+        let url = format!("{}/{}/day/{day}/answer", self.base_url, self.year);
+        let form =
+            format!("level={}&answer={}", part.number(), encode(answer));
+        let response = self.request(&url, Some(&form))?;
+        Submission::classify(&response.body).ok_or_else(|| {
+            Error::Client(format!(
+                "{url}: unrecognised response (HTTP {})",
+                response.status
+            ))
+        })
+    }
+
+    // GET, or POST when there is a form body
+    fn request(&self, url: &str, form: Option<&str>) -> Result<Response> {
+        match url.strip_prefix("http://") {
+            Some(rest) => self.request_tcp(rest, form),
+            None => self.request_curl(url, form),
+        }
+        .map_err(|message| Error::Client(format!("{url}: {message}")))
+    }
+
+    fn request_tcp(
+        &self,
+        rest: &str,
+        form: Option<&str>,
+    ) -> Result<Response, String> {
+        let (host, path) = match rest.find('/') {
+            Some(i) => (&rest[..i], &rest[i..]),
+            None => (rest, "/"),
+        };
+        let address = if host.contains(':') {
+            host.to_string()
+        } else {
+            format!("{host}:80")
+        };
+
+        let mut request = format!(
+            "{} {path} HTTP/1.1\r\nHost: {host}\r\nUser-Agent: {USER_AGENT}\r\n\
+             Cookie: session={}\r\nConnection: close\r\n",
+            if form.is_some() { "POST" } else { "GET" },
+            self.session
+        );
+        if let Some(form) = form {
+            request += "Content-Type: application/x-www-form-urlencoded\r\n";
+            request += &format!("Content-Length: {}\r\n", form.len());
+        }
+        request += "\r\n";
+        request += form.unwrap_or("");
+
+        let io_error = |err: std::io::Error| err.to_string();
+        let mut stream = TcpStream::connect(&address).map_err(io_error)?;
+        stream.set_read_timeout(Some(TIMEOUT)).map_err(io_error)?;
+        stream.write_all(request.as_bytes()).map_err(io_error)?;
+        let mut raw = Vec::new();
+        stream.read_to_end(&mut raw).map_err(io_error)?;
+        parse_response(&raw).ok_or_else(|| "malformed response".to_string())
+    }
+
+    fn request_curl(
+        &self,
+        url: &str,
+        form: Option<&str>,
+    ) -> Result<Response, String> {
+        let mut command = Command::new("curl");
+        // The cookie goes through stdin to keep it out of the process list
+        command.args(["-sS", "-A", USER_AGENT, "-H", "@-"]).args([
+            "-w",
+            "\n%{http_code}",
+            url,
+        ]);
+        if let Some(form) = form {
+            command.args(["--data-raw", form]);
+        }
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(curl_error)?;
+        if let Some(mut stdin) = child.stdin.take() {
+            writeln!(stdin, "Cookie: session={}", self.session)
+                .map_err(|err| err.to_string())?;
+        }
+        let output =
+            child.wait_with_output().map_err(|err| err.to_string())?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("curl failed: {}", stderr.trim()));
+        }
+
+        let text = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = text.rsplit_once('\n').unwrap_or(("", &text));
+        let status = status
+            .trim()
+            .parse()
+            .map_err(|_| "curl gave no status code".to_string())?;
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+// Why curl could not be started, pointing at the fix if it is missing
+fn curl_error(err: io::Error) -> String {
+    if err.kind() == io::ErrorKind::NotFound {
+        "curl is needed for https:// URLs but was not found on PATH; \
+         install it, or point $AOC_BASE_URL at an http:// server"
+            .to_string()
+    } else {
+        format!("cannot run curl: {err}")
+    }
+}
+
+fn parse_response(raw: &[u8]) -> Option<Response> {
+    let text = String::from_utf8_lossy(raw);
+    let (head, body) = text.split_once("\r\n\r\n")?;
+    let mut lines = head.lines();
+    let status = lines.next()?.split_whitespace().nth(1)?.parse().ok()?;
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.eq_ignore_ascii_case("transfer-encoding")
+                && value.trim().eq_ignore_ascii_case("chunked")
+        })
+    });
+    let body = if chunked {
+        dechunk(body)?
+    } else {
+        body.to_string()
+    };
+    Some(Response { status, body })
+}
+
+fn dechunk(mut body: &str) -> Option<String> {
+    let mut result = String::new();
+    loop {
+        let (size, rest) = body.split_once("\r\n")?;
+        let size =
+            usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        if size == 0 {
+            return Some(result);
+        }
+        result.push_str(rest.get(..size)?);
+        body = rest.get(size..)?.strip_prefix("\r\n")?;
+    }
+}
+
+// Percent-encodes a form value
+fn encode(value: &str) -> String {
+    let mut result = String::new();
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' => {
+                result.push(byte as char)
+            }
+            _ => result.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    // Serves one canned response and hands back the request it received
+    fn mock(response: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 4096];
+            loop {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request);
+                if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                    let length = head
+                        .lines()
+                        .find_map(|l| l.strip_prefix("Content-Length: "))
+                        .map_or(0, |n| n.parse().unwrap());
+                    if body.len() >= length {
+                        break;
+                    }
+                }
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn fetch() {
+        let (url, server) = mock(
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n\
+             4\r\n1 2\n\r\n4\r\n3 4\n\r\n0\r\n\r\n",
+        );
+        let client = Client::new(&url, "abc", 2025);
+        assert_eq!(client.fetch_input(3).unwrap(), "1 2\n3 4\n");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2025/day/3/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+    }
+
+    #[test]
+    fn submit() {
+        let (url, server) = mock(
+            "HTTP/1.1 200 OK\r\nContent-Length: 67\r\n\r\n\
+             <article><p>That's not the right answer; your answer is too \
+             low.",
+        );
+        let client = Client::new(&url, "abc", 2024);
+        let outcome = client.submit(2, Part::Two, "1 2").unwrap();
+        assert_eq!(outcome, Submission::TooLow);
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/2/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=1%202"));
+    }
+
+    #[test]
+    fn missing_curl() {
+        let err = curl_error(io::ErrorKind::NotFound.into());
+        assert!(err.starts_with("curl is needed for https:// URLs"), "{err}");
+        let err = curl_error(io::ErrorKind::PermissionDenied.into());
+        assert!(err.starts_with("cannot run curl: "), "{err}");
+    }
+
+    #[test]
+    fn classify() {
+        let classify = Submission::classify;
+        assert_eq!(
+            classify("That's the right answer!"),
+            Some(Submission::Correct)
+        );
+        assert_eq!(
+            classify("That's not the right answer; your answer is too high"),
+            Some(Submission::TooHigh)
+        );
+        assert_eq!(
+            classify(
+                "You gave an answer too recently; you have to wait after \
+                 submitting an answer before trying again.  You have 1m 5s \
+                 left to wait."
+            ),
+            Some(Submission::RateLimited {
+                wait: Some(Duration::from_secs(65))
+            })
+        );
+        assert_eq!(
+            classify("You don't seem to be solving the right level."),
+            Some(Submission::AlreadySolved)
+        );
+        assert_eq!(classify("<html></html>"), None);
+    }
+}
@@ -0,0 +1,150 @@
+/// Writes Graphviz DOT text, quoting every name and value so that any
+/// string can be used.
+#[derive(Debug, Clone)]
+pub struct Dot {
+    text: String,
+    edge: &'static str,
+    depth: usize,
+}
+
+/// Attributes as `(name, value)` pairs.
+pub type Attrs<'a> = &'a [(&'a str, &'a str)];
+
+impl Dot {
+    /// A directed graph, whose edges are drawn as arrows.
+    pub fn digraph(name: &str) -> Self {
+        Self::open("digraph", name, "->")
+    }
+
+    /// An undirected graph.
+    pub fn graph(name: &str) -> Self {
+        Self::open("graph", name, "--")
+    }
+
+    fn open(kind: &str, name: &str, edge: &'static str) -> Self {
+        Self {
+            text: format!("{kind} {} {{\n", quote(name)),
+            edge,
+            depth: 1,
+        }
+    }
+
+    /// Sets an attribute of the graph or subgraph being written.
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.line(&format!("{name}={}", quote(value)));
+    }
+
+    /// Attributes for every `node` or `edge` written after this.
+    pub fn defaults(&mut self, kind: &str, attrs: Attrs) {
+        self.line(&format!("{kind}{}", list(attrs)));
+    }
+
+    pub fn node(&mut self, id: &str, attrs: Attrs) {
+        self.line(&format!("{}{}", quote(id), list(attrs)));
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, attrs: Attrs) {
+        let (from, to) = (quote(from), quote(to));
+        self.line(&format!("{from} {} {to}{}", self.edge, list(attrs)));
+    }
+
+    /// Starts a subgraph; one named `cluster...` is drawn boxed in.
+    pub fn begin(&mut self, name: &str) {
+        self.line(&format!("subgraph {} {{", quote(name)));
+        self.depth += 1;
+    }
+
+    pub fn end(&mut self) {
+        self.depth -= 1;
+        self.line("}");
+    }
+
+    pub fn comment(&mut self, text: &str) {
+        for line in text.lines() {
+            self.line(&format!("// {line}"));
+        }
+    }
+
+    /// The finished text, closing any subgraphs still open.
+    pub fn finish(mut self) -> String {
+        while self.depth > 0 {
+            self.end();
+        }
+        self.text
+    }
+
+    fn line(&mut self, line: &str) {
+        for _ in 0..self.depth {
+            self.text.push_str("    ");
+        }
+        self.text.push_str(line);
+        self.text.push('\n');
+    }
+}
+
+// `[name="value", ...]`, or nothing without attributes
+fn list(attrs: Attrs) -> String {
+    if attrs.is_empty() {
+        return String::new();
+    }
+    let attrs: Vec<String> = (attrs.iter())
+        .map(|&(name, value)| format!("{name}={}", quote(value)))
+        .collect();
+    format!(" [{}]", attrs.join(", "))
+}
+
+// A DOT string, where a line break becomes `\n`
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write() {
+        let mut dot = Dot::digraph("day 11");
+        dot.defaults("node", &[("shape", "box")]);
+        dot.begin("cluster_0");
+        dot.set("label", "say \"hi\"");
+        dot.node("a", &[("label", "a\n2")]);
+        dot.end();
+        dot.comment("two\nlines");
+        dot.edge("a", "b\\c", &[]);
+        assert_eq!(
+            dot.finish(),
+            r#"digraph "day 11" {
+    node [shape="box"]
+    subgraph "cluster_0" {
+        label="say \"hi\""
+        "a" [label="a\n2"]
+    }
+    // two
+    // lines
+    "a" -> "b\\c"
+}
+"#
+        );
+
+        let mut dot = Dot::graph("g");
+        dot.begin("inner");
+        dot.edge("1", "2", &[("color", "red")]);
+        assert_eq!(
+            dot.finish(),
+            "graph \"g\" {\n    subgraph \"inner\" {\n        \
+             \"1\" -- \"2\" [color=\"red\"]\n    }\n}\n"
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::Problem;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum Error {
+    Parse(ParseError),
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// A malformed harness file, such as the recorded answers.
+    Invalid {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+    /// A real input that fails its integrity checks.
+    BadInput {
+        path: PathBuf,
+        problem: Problem,
+    },
+    /// Input that parsed fine but has no answer to give, such as a path
+    /// count through a device the input lacks.
+    Unsolvable {
+        day: u8,
+        message: String,
+    },
+    /// Talking to the puzzle site failed, or it sent something unexpected.
+    Client(String),
+    /// A solver stopped early because its [`crate::Cancel`] token fired.
+    Cancelled,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::Io { path, source } => {
+                write!(f, "{}: {source}", path.display())
+            }
+            Error::Invalid {
+                path,
+                line,
+                message,
+            } => write!(f, "{}:{line}: {message}", path.display()),
+            Error::BadInput { path, problem } => {
+                write!(f, "{}: {problem}", path.display())
+            }
+            Error::Unsolvable { day, message } => {
+                write!(f, "day {day}: {message}")
+            }
+            Error::Client(message) => f.write_str(message),
+            Error::Cancelled => f.write_str("cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// Malformed puzzle input. `line` and `column` are 1-based; zero means
+/// the position is unknown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        day: u8,
+        line: usize,
+        column: usize,
+        text: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Builds an error for `text`, which must be a subslice of `input`;
+    /// the line and column are worked out from where it sits.
+    pub fn locate(
+        day: u8,
+        input: &str,
+        text: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        let (line, column) = position(input, text).unwrap_or((0, 0));
+        Self::new(day, line, column, text, message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}", self.day)?;
+        if self.line > 0 {
+            write!(f, ", line {}, column {}", self.line, self.column)?;
+        }
+        write!(f, ": {}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, " `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `text`, a subslice of `input`, reporting its position on failure.
+pub(crate) fn parse<T: FromStr>(
+    day: u8,
+    input: &str,
+    text: &str,
+) -> Result<T, ParseError> {
+    text.trim()
+        .parse()
+        .map_err(|_| ParseError::locate(day, input, text, "invalid number"))
+}
+
+/// Rejects the first character of `text`, a subslice of `input`, that is
+/// not one of `allowed`.
+pub(crate) fn expect_chars(
+    day: u8,
+    input: &str,
+    text: &str,
+    allowed: &str,
+) -> Result<(), ParseError> {
+    match text.char_indices().find(|&(_, c)| !allowed.contains(c)) {
+        Some((i, c)) => Err(ParseError::locate(
+            day,
+            input,
+            &text[i..i + c.len_utf8()],
+            "unexpected character",
+        )),
+        None => Ok(()),
+    }
+}
+
+fn position(input: &str, text: &str) -> Option<(usize, usize)> {
+    let start = input.as_ptr() as usize;
+    let offset = (text.as_ptr() as usize).checked_sub(start)?;
+    if offset + text.len() > input.len() {
+        return None;
+    }
+
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    Some((line, column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_subslice() {
+        let input = "L68\nR4x\n";
+        let field = &input.lines().nth(1).unwrap()[1..];
+        let err = parse::<i32>(1, input, field).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.text, "4x");
+        assert_eq!(
+            err.to_string(),
+            "day 1, line 2, column 2: invalid number `4x`"
+        );
+    }
+
+    #[test]
+    fn locate_foreign_text() {
+        let err = ParseError::locate(3, "abc", "xyz", "unexpected");
+        assert_eq!((err.line, err.column), (0, 0));
+        assert_eq!(err.to_string(), "day 3: unexpected `xyz`");
+    }
+}
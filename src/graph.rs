@@ -0,0 +1,1244 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::mem;
+
+use crate::{BigUint, ParseError, Rng};
+
+/// A node's index in its [`Graph`], handed out in order of first mention.
+pub type NodeId = usize;
+
+/// A directed cycle, listed from the node it starts and ends at; the edge
+/// back to the first node is implied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle(pub Vec<NodeId>);
+
+/// Most waypoints a [`Route`] can visit in any order when its paths are
+/// listed or sampled, as a path's progress is the subset of them seen.
+pub const MAX_UNORDERED: usize = 20;
+
+/// Why [`Graph::route_paths`] has no paths to give.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteError {
+    /// More than [`MAX_UNORDERED`] distinct waypoints in any order.
+    TooManyWaypoints(usize),
+    /// Some paths can go round this cycle as often as they like.
+    Infinite(Cycle),
+}
+
+impl From<Cycle> for RouteError {
+    fn from(cycle: Cycle) -> Self {
+        RouteError::Infinite(cycle)
+    }
+}
+
+/// The paths [`Graph::count_route`] counts: those from `from` to `to` that
+/// visit every node in `via` and none in `avoid`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub from: NodeId,
+    pub to: NodeId,
+    pub via: Vec<NodeId>,
+    /// Whether the waypoints must be reached in the order given.
+    pub ordered: bool,
+    pub avoid: Vec<NodeId>,
+}
+
+impl Route {
+    /// Every path from `from` to `to`.
+    pub fn new(from: NodeId, to: NodeId) -> Self {
+        Self {
+            from,
+            to,
+            via: Vec::new(),
+            ordered: false,
+            avoid: Vec::new(),
+        }
+    }
+}
+
+/// A directed graph over named nodes, stored as adjacency lists.
+///
+/// Names are interned once, so the algorithms work on plain indices.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    edges: Vec<Vec<NodeId>>,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses lines of `node: successor successor ...`, skipping blank
+    /// lines and `#` comments. A node may be listed more than once.
+    pub fn parse(day: u8, input: &str) -> Result<Self, ParseError> {
+        let mut graph = Self::new();
+        let lines = input
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+        for line in lines {
+            let (from, to) = line.split_once(':').ok_or_else(|| {
+                let message = "expected `node: successors`";
+                ParseError::locate(day, input, line, message)
+            })?;
+            if from.trim().is_empty() {
+                let message = "missing node name";
+                return Err(ParseError::locate(day, input, line, message));
+            }
+            let from = graph.add_node(from.trim());
+            for name in to.split_whitespace() {
+                let to = graph.add_node(name);
+                graph.edges[from].push(to);
+            }
+        }
+        Ok(graph)
+    }
+
+    /// The ID of `name`, adding it as a node if it is new.
+    pub fn add_node(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    pub fn add_edge(&mut self, from: &str, to: &str) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.edges[from].push(to);
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    /// The nodes `id` has an edge to, in the order they were added.
+    pub fn successors(&self, id: NodeId) -> &[NodeId] {
+        &self.edges[id]
+    }
+
+    /// Every edge as `(from, to)`, grouped by `from`.
+    pub fn edges(&self) -> impl Iterator<Item = (NodeId, NodeId)> + '_ {
+        (self.edges.iter().enumerate())
+            .flat_map(|(from, to)| to.iter().map(move |&to| (from, to)))
+    }
+
+    /// `nodes` by name, joined by arrows; a cycle comes back to its start.
+    pub fn format_path(&self, nodes: &[NodeId]) -> String {
+        let names: Vec<&str> =
+            nodes.iter().map(|&id| self.name(id)).collect();
+        names.join(" -> ")
+    }
+
+    pub fn format_cycle(&self, Cycle(nodes): &Cycle) -> String {
+        let back = nodes.first().copied();
+        let closed: Vec<NodeId> = nodes.iter().copied().chain(back).collect();
+        self.format_path(&closed)
+    }
+
+    /// Every node, each before all the nodes it has an edge to.
+    pub fn topo_sort(&self) -> Result<Vec<NodeId>, Cycle> {
+        self.topo_order(0..self.len(), |_| true)
+    }
+
+    pub fn find_cycle(&self) -> Option<Cycle> {
+        self.topo_sort().err()
+    }
+
+    /// The strongly connected components, each with its nodes in
+    /// increasing order. A component comes before every component it has
+    /// an edge to.
+    pub fn components(&self) -> Vec<Vec<NodeId>> {
+        // Kosaraju: taking nodes by falling finish time, each search of
+        // the reversed graph picks out exactly one component
+        let mut finished = finish_order(&self.edges);
+        finished.reverse();
+        let reversed = self.reversed();
+        let mut found = vec![false; self.len()];
+        let mut components = Vec::new();
+        for root in finished {
+            if found[root] {
+                continue;
+            }
+            found[root] = true;
+            let mut members = vec![root];
+            let mut i = 0;
+            while let Some(&node) = members.get(i) {
+                i += 1;
+                for &prev in &reversed[node] {
+                    if !found[prev] {
+                        found[prev] = true;
+                        members.push(prev);
+                    }
+                }
+            }
+            members.sort_unstable();
+            components.push(members);
+        }
+        components
+    }
+
+    // The predecessors of every node
+    fn reversed(&self) -> Vec<Vec<NodeId>> {
+        let mut reversed = vec![Vec::new(); self.len()];
+        for (from, to) in self.edges() {
+            reversed[to].push(from);
+        }
+        reversed
+    }
+
+    /// The nodes reachable from `roots` through nodes that pass `keep`,
+    /// in topological order.
+    fn topo_order(
+        &self,
+        roots: impl IntoIterator<Item = NodeId>,
+        keep: impl Fn(NodeId) -> bool,
+    ) -> Result<Vec<NodeId>, Cycle> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            New,
+            Open,
+            Done,
+        }
+
+        let mut state = vec![State::New; self.len()];
+        let mut finished = Vec::new();
+        // Depth-first without recursion: each node with its next edge
+        let mut stack: Vec<(NodeId, usize)> = Vec::new();
+        for root in roots {
+            if state[root] != State::New || !keep(root) {
+                continue;
+            }
+            state[root] = State::Open;
+            stack.push((root, 0));
+            while let Some((node, next)) = stack.last_mut() {
+                let Some(&to) = self.edges[*node].get(*next) else {
+                    state[*node] = State::Done;
+                    finished.push(*node);
+                    stack.pop();
+                    continue;
+                };
+                *next += 1;
+                match state[to] {
+                    State::New if keep(to) => {
+                        state[to] = State::Open;
+                        stack.push((to, 0));
+                    }
+                    State::Open => {
+                        // `to` is on the stack, so the stack closes a loop
+                        let start =
+                            stack.iter().position(|&(n, _)| n == to).unwrap();
+                        let cycle = stack[start..].iter().map(|&(n, _)| n);
+                        return Err(Cycle(cycle.collect()));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        finished.reverse();
+        Ok(finished)
+    }
+
+    // Path counts from `from`, pushed forward through `order`
+    fn count_along(&self, from: NodeId, order: &[NodeId]) -> Vec<BigUint> {
+        let mut counts = vec![BigUint::zero(); self.len()];
+        counts[from] = BigUint::from(1u64);
+        for &node in order {
+            for &to in &self.edges[node] {
+                counts[to] = &counts[to] + &counts[node];
+            }
+        }
+        counts
+    }
+
+    /// The number of paths from `from` to `to`.
+    ///
+    /// Cycles that no such path can enter are ignored. A cycle that one can
+    /// enter repeats as often as it likes, so there are infinitely many
+    /// paths; that cycle is the error.
+    pub fn count_paths(
+        &self,
+        from: NodeId,
+        to: NodeId,
+    ) -> Result<BigUint, Cycle> {
+        // Only nodes that lead on to `to` can be on a path
+        let leads_to = reachable(&self.reversed(), to);
+        let order = self.topo_order([from], |node| leads_to[node])?;
+        Ok(self.count_along(from, &order).swap_remove(to))
+    }
+
+    /// The number of paths from `from` to `to` that pass through every one
+    /// of `waypoints`, in whatever order, or the cycle that makes it
+    /// infinite.
+    ///
+    /// A path meets the strongly connected components in topological
+    /// order, so only one order of waypoints in different components can
+    /// work and the count is a product of the legs between consecutive
+    /// ones. Two waypoints sharing a component sit on a cycle, and any
+    /// order of them gives the same verdict.
+    pub fn count_paths_via(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        waypoints: &[NodeId],
+    ) -> Result<BigUint, Cycle> {
+        let mut rank = vec![0; self.len()];
+        if !waypoints.is_empty() {
+            for (i, members) in self.components().iter().enumerate() {
+                for &node in members {
+                    rank[node] = i;
+                }
+            }
+        }
+        let mut stops: Vec<NodeId> = waypoints.to_vec();
+        stops.sort_unstable_by_key(|&node| (rank[node], node));
+        stops.dedup();
+        stops.insert(0, from);
+        stops.push(to);
+
+        // A leg with no paths wins over one with infinitely many
+        let mut total = Ok(BigUint::from(1u64));
+        for leg in stops.windows(2) {
+            match self.count_paths(leg[0], leg[1]) {
+                Ok(count) if count.is_zero() => return Ok(count),
+                Ok(count) => total = total.map(|total| total * count),
+                Err(cycle) => total = total.and(Err(cycle)),
+            }
+        }
+        total
+    }
+
+    /// The number of paths along `route`. See [`Graph::route_paths`].
+    ///
+    /// Without an order or nodes to avoid this is
+    /// [`Graph::count_paths_via`], which takes any number of waypoints.
+    pub fn count_route(&self, route: &Route) -> Result<BigUint, RouteError> {
+        if !route.ordered && route.avoid.is_empty() {
+            let count =
+                self.count_paths_via(route.from, route.to, &route.via);
+            return Ok(count?);
+        }
+        Ok(self.route_paths(route)?.count())
+    }
+
+    /// The paths along `route`, ready to be counted, listed or sampled, or
+    /// a cycle that some of them can go round as often as they like.
+    ///
+    /// A path is tracked together with its progress along the route, i.e.
+    /// the set of waypoints seen so far or, for an ordered route, how many.
+    /// Progress never goes back, so cycles are only those of the graph and
+    /// a DP over the pairs in topological order does the counting. Only
+    /// cycles on a path that completes the route make it infinite.
+    ///
+    /// Only pairs reachable from the start are stored, and a pair is
+    /// dropped once the goal or a waypoint still to come is out of reach.
+    /// Without cycles that leaves one state per node, since the waypoints
+    /// seen must be exactly those a node cannot go on to.
+    ///
+    /// An unordered route can have at most [`MAX_UNORDERED`] waypoints.
+    pub fn route_paths(
+        &self,
+        route: &Route,
+    ) -> Result<RoutePaths<'_>, RouteError> {
+        let mut slot = vec![None; self.len()];
+        let mut waypoints = Vec::new();
+        for &node in &route.via {
+            if slot[node].is_none() {
+                slot[node] = Some(waypoints.len());
+                waypoints.push(node);
+            }
+        }
+        if !route.ordered && waypoints.len() > MAX_UNORDERED {
+            return Err(RouteError::TooManyWaypoints(waypoints.len()));
+        }
+        let mut avoid = vec![false; self.len()];
+        for &node in &route.avoid {
+            avoid[node] = true;
+        }
+        // The predecessors of every node, leaving out the avoided ones
+        let mut reversed = vec![Vec::new(); self.len()];
+        for (from, to) in self.edges() {
+            if !avoid[from] && !avoid[to] {
+                reversed[to].push(from);
+            }
+        }
+
+        let progress = Progress {
+            graph: self,
+            slot,
+            reaches: (waypoints.iter())
+                .map(|&node| reachable(&reversed, node))
+                .collect(),
+            leads_to: reachable(&reversed, route.to),
+            avoid,
+            ordered: route.ordered,
+            states: if route.ordered {
+                waypoints.len() + 1
+            } else {
+                1 << waypoints.len()
+            },
+        };
+        let states = progress.states;
+        let start = (progress.step(0, route.from))
+            .map(|state| route.from * states + state);
+        Ok(progress.analyse(start, route.to * states + states - 1)?)
+    }
+}
+
+/// The paths along a [`Route`], from [`Graph::route_paths`]. A path is
+/// given as every node it visits, `from` and `to` included.
+///
+/// Parallel edges make paths that are counted and listed separately even
+/// though their nodes are the same.
+pub struct RoutePaths<'a> {
+    progress: Progress<'a>,
+    start: Option<usize>,
+    goal: usize,
+    // The number of paths from each pair to the goal, for just the pairs
+    // that lead there
+    ways: HashMap<usize, BigUint>,
+    // The fewest edges from each pair that leads to the goal
+    distance: HashMap<usize, usize>,
+    // The pairs that lead to the goal, sinks first
+    order: Vec<usize>,
+    // Each node's position in order of name
+    rank: Vec<usize>,
+}
+
+impl RoutePaths<'_> {
+    pub fn count(&self) -> BigUint {
+        (self.start())
+            .map_or(BigUint::zero(), |start| self.ways[&start].clone())
+    }
+
+    /// Every path, in lexicographic order of node names. Each one takes
+    /// time in proportion to its length, as no branch is a dead end.
+    pub fn iter(&self) -> Paths<'_> {
+        let stack = (self.start().into_iter())
+            .map(|start| (start, self.children(start)))
+            .collect();
+        Paths {
+            paths: self,
+            stack,
+            ready: self.start() == Some(self.goal),
+        }
+    }
+
+    /// The `k` paths with the fewest edges, ties broken by lexicographic
+    /// order of node names.
+    ///
+    /// A best-first search over partial paths, whose distance to the goal
+    /// is known exactly, so each complete path comes off the queue in
+    /// order and no partial path is a dead end.
+    pub fn shortest(&self, k: usize) -> Vec<Vec<NodeId>> {
+        let mut found = Vec::new();
+        let mut queue = BinaryHeap::new();
+        if let Some(start) = self.start() {
+            let ranks = vec![self.rank_of(start)];
+            queue.push(Reverse((self.distance[&start], ranks, vec![start])));
+        }
+        while found.len() < k {
+            let Some(Reverse((_, ranks, pairs))) = queue.pop() else {
+                break;
+            };
+            let last = pairs[pairs.len() - 1];
+            if last == self.goal {
+                found.push(self.nodes(&pairs));
+                continue;
+            }
+            for next in self.children(last) {
+                let (mut ranks, mut pairs) = (ranks.clone(), pairs.clone());
+                ranks.push(self.rank_of(next));
+                pairs.push(next);
+                let estimate = pairs.len() - 1 + self.distance[&next];
+                queue.push(Reverse((estimate, ranks, pairs)));
+            }
+        }
+        found
+    }
+
+    /// A path picked uniformly at random, or None if there are none.
+    ///
+    /// Each step goes on to a successor in proportion to the number of
+    /// paths it leads to.
+    pub fn sample(&self, rng: &mut Rng) -> Option<Vec<NodeId>> {
+        let mut pair = self.start()?;
+        let mut path = vec![pair / self.progress.states];
+        loop {
+            let mut pick = rng.below_big(&self.ways[&pair]);
+            if pair == self.goal {
+                if pick.is_zero() {
+                    return Some(path);
+                }
+                pick = pick.checked_sub(&BigUint::from(1u64)).unwrap();
+            }
+            pair = (self.progress.successors(pair))
+                .filter(|next| self.leads(next))
+                .find(|next| match pick.checked_sub(&self.ways[next]) {
+                    Some(rest) => {
+                        pick = rest;
+                        false
+                    }
+                    None => true,
+                })
+                .unwrap();
+            path.push(pair / self.progress.states);
+        }
+    }
+
+    /// How many of the paths visit each node.
+    pub fn visits(&self) -> Vec<BigUint> {
+        // Paths through a pair are those reaching it times those leaving
+        let states = self.progress.states;
+        let mut reaching: HashMap<usize, BigUint> = HashMap::new();
+        if let Some(start) = self.start() {
+            reaching.insert(start, BigUint::from(1u64));
+        }
+        let mut visits = vec![BigUint::zero(); self.progress.graph.len()];
+        for pair in self.order.iter().rev() {
+            let Some(here) = reaching.remove(pair) else {
+                continue;
+            };
+            visits[pair / states] += &here * &self.ways[pair];
+            for next in self.progress.successors(*pair) {
+                if self.leads(&next) {
+                    *reaching.entry(next).or_default() += &here;
+                }
+            }
+        }
+        visits
+    }
+
+    fn start(&self) -> Option<usize> {
+        self.start.filter(|start| self.leads(start))
+    }
+
+    // Whether `pair` can go on to reach the goal
+    fn leads(&self, pair: &usize) -> bool {
+        self.ways.contains_key(pair)
+    }
+
+    fn rank_of(&self, pair: usize) -> usize {
+        self.rank[pair / self.progress.states]
+    }
+
+    // The successors of `pair` that lead to the goal, last name first
+    fn children(&self, pair: usize) -> Vec<usize> {
+        let mut children: Vec<usize> = (self.progress.successors(pair))
+            .filter(|next| self.leads(next))
+            .collect();
+        children.sort_by_key(|&next| Reverse(self.rank_of(next)));
+        children
+    }
+
+    fn nodes(&self, pairs: &[usize]) -> Vec<NodeId> {
+        pairs
+            .iter()
+            .map(|pair| pair / self.progress.states)
+            .collect()
+    }
+}
+
+/// The paths along a route in lexicographic order of node names, from
+/// [`RoutePaths::iter`].
+pub struct Paths<'a> {
+    paths: &'a RoutePaths<'a>,
+    // The path so far, each pair with the successors still to try
+    stack: Vec<(usize, Vec<usize>)>,
+    // Whether the path on the stack is complete and not yet returned
+    ready: bool,
+}
+
+impl Iterator for Paths<'_> {
+    type Item = Vec<NodeId>;
+
+    fn next(&mut self) -> Option<Vec<NodeId>> {
+        if !mem::take(&mut self.ready) {
+            loop {
+                let (_, untried) = self.stack.last_mut()?;
+                let Some(next) = untried.pop() else {
+                    self.stack.pop();
+                    continue;
+                };
+                self.stack.push((next, self.paths.children(next)));
+                if next == self.paths.goal {
+                    break;
+                }
+            }
+        }
+        let pairs: Vec<usize> =
+            self.stack.iter().map(|&(pair, _)| pair).collect();
+        Some(self.paths.nodes(&pairs))
+    }
+}
+
+// The graph paired with progress along a route, numbered as
+// `node * states + state`. The last state has seen every waypoint.
+struct Progress<'a> {
+    graph: &'a Graph,
+    // Each waypoint's position among the distinct waypoints
+    slot: Vec<Option<usize>>,
+    // For each waypoint, the nodes that can go on to it
+    reaches: Vec<Vec<bool>>,
+    // The nodes that can go on to the end of the route
+    leads_to: Vec<bool>,
+    avoid: Vec<bool>,
+    ordered: bool,
+    states: usize,
+}
+
+impl<'a> Progress<'a> {
+    // The state after entering `node`, or None if the path is ruled out
+    // or can no longer complete the route
+    fn step(&self, state: usize, node: NodeId) -> Option<usize> {
+        if self.avoid[node] || !self.leads_to[node] {
+            return None;
+        }
+        let state = match self.slot[node] {
+            None => state,
+            Some(i) if !self.ordered => state | 1 << i,
+            Some(i) if i == state => state + 1,
+            // Seen already, which takes a cycle, or reached too early
+            Some(i) => (i < state).then_some(state)?,
+        };
+        let ahead = if self.ordered {
+            self.reaches.get(state).is_none_or(|reaches| reaches[node])
+        } else {
+            (self.reaches.iter().enumerate())
+                .all(|(i, reaches)| state & 1 << i != 0 || reaches[node])
+        };
+        ahead.then_some(state)
+    }
+
+    fn successors(&self, pair: usize) -> impl Iterator<Item = usize> + '_ {
+        let (node, state) = (pair / self.states, pair % self.states);
+        self.graph.edges[node].iter().filter_map(move |&to| {
+            Some(to * self.states + self.step(state, to)?)
+        })
+    }
+
+    // Works out the paths from `start` to `goal`, finding the strongly
+    // connected components with Tarjan's algorithm. They close sinks
+    // first, so everything about the way on from a component is known as
+    // it closes.
+    fn analyse(
+        self,
+        start: Option<usize>,
+        goal: usize,
+    ) -> Result<RoutePaths<'a>, Cycle> {
+        const NEW: usize = usize::MAX;
+        // Pairs are numbered in the order they are found, which is also
+        // their index in Tarjan's algorithm, so only those found take room
+        let mut found: HashMap<usize, usize> = HashMap::new();
+        let mut pairs = Vec::new();
+        let mut low = Vec::new();
+        let mut component = Vec::new();
+        let mut ways = HashMap::new();
+        let mut distance = HashMap::new();
+        let mut open = Vec::new();
+        let mut order = Vec::new();
+
+        let mut stack = Vec::new();
+        if let Some(pair) = start {
+            found.insert(pair, 0);
+            pairs.push(pair);
+            low.push(0);
+            component.push(NEW);
+            open.push(0);
+            stack.push((0, 0));
+        }
+        while let Some((at, edge)) = stack.last_mut() {
+            let at = *at;
+            let pair = pairs[at];
+            let (node, state) = (pair / self.states, pair % self.states);
+            if let Some(&to) = self.graph.edges[node].get(*edge) {
+                *edge += 1;
+                let Some(next) = self.step(state, to) else {
+                    continue;
+                };
+                let next = to * self.states + next;
+                match found.get(&next) {
+                    Some(&seen) if component[seen] == NEW => {
+                        low[at] = low[at].min(seen);
+                    }
+                    Some(_) => {}
+                    None => {
+                        let seen = pairs.len();
+                        found.insert(next, seen);
+                        pairs.push(next);
+                        low.push(seen);
+                        component.push(NEW);
+                        open.push(seen);
+                        stack.push((seen, 0));
+                    }
+                }
+                continue;
+            }
+
+            stack.pop();
+            if let Some(&(parent, _)) = stack.last() {
+                low[parent] = low[parent].min(low[at]);
+            }
+            if low[at] != at {
+                continue;
+            }
+            let split = open.iter().rposition(|&member| member == at);
+            let members = open.split_off(split.unwrap());
+            for &member in &members {
+                component[member] = at;
+            }
+            let mut reaches = members.iter().any(|&m| pairs[m] == goal);
+            let mut cyclic = false;
+            for &member in &members {
+                for next in self.successors(pairs[member]) {
+                    if component[found[&next]] == at {
+                        cyclic = true;
+                    } else {
+                        reaches |= ways.contains_key(&next);
+                    }
+                }
+            }
+            if !reaches {
+                continue;
+            }
+            if cyclic {
+                let within = |next: usize| component[found[&next]] == at;
+                return Err(self.cycle(pair, within));
+            }
+
+            // Without a cycle the component is just `pair`
+            let (mut here, mut fewest) = if pair == goal {
+                (BigUint::from(1u64), 0)
+            } else {
+                (BigUint::zero(), usize::MAX)
+            };
+            for next in self.successors(pair) {
+                if let Some(there) = ways.get(&next) {
+                    here += there;
+                    fewest = fewest.min(distance[&next] + 1);
+                }
+            }
+            ways.insert(pair, here);
+            distance.insert(pair, fewest);
+            order.push(pair);
+        }
+
+        let mut by_name: Vec<NodeId> = (0..self.graph.len()).collect();
+        by_name.sort_by_key(|&node| self.graph.name(node));
+        let mut rank = vec![0; self.graph.len()];
+        for (i, node) in by_name.into_iter().enumerate() {
+            rank[node] = i;
+        }
+        Ok(RoutePaths {
+            progress: self,
+            start,
+            goal,
+            ways,
+            distance,
+            order,
+            rank,
+        })
+    }
+
+    // A cycle through the component `from` closed in, whose pairs pass
+    // `within`; it stays in one state and so is a cycle of the graph
+    fn cycle(&self, from: usize, within: impl Fn(usize) -> bool) -> Cycle {
+        let mut walked = vec![from];
+        let mut seen = HashMap::from([(from, 0)]);
+        loop {
+            let last = walked[walked.len() - 1];
+            let next =
+                self.successors(last).find(|&next| within(next)).unwrap();
+            if let Some(&start) = seen.get(&next) {
+                let nodes = walked[start..].iter().map(|p| p / self.states);
+                return Cycle(nodes.collect());
+            }
+            seen.insert(next, walked.len());
+            walked.push(next);
+        }
+    }
+}
+
+// Every node in order of when a depth-first search finished with it
+fn finish_order(edges: &[Vec<NodeId>]) -> Vec<NodeId> {
+    let mut seen = vec![false; edges.len()];
+    let mut finished = Vec::with_capacity(edges.len());
+    let mut stack: Vec<(NodeId, usize)> = Vec::new();
+    for root in 0..edges.len() {
+        if seen[root] {
+            continue;
+        }
+        seen[root] = true;
+        stack.push((root, 0));
+        while let Some((node, next)) = stack.last_mut() {
+            match edges[*node].get(*next) {
+                Some(&to) => {
+                    *next += 1;
+                    if !seen[to] {
+                        seen[to] = true;
+                        stack.push((to, 0));
+                    }
+                }
+                None => {
+                    finished.push(*node);
+                    stack.pop();
+                }
+            }
+        }
+    }
+    finished
+}
+
+// Which nodes can be reached from `from` along `edges`
+fn reachable(edges: &[Vec<NodeId>], from: NodeId) -> Vec<bool> {
+    let mut seen = vec![false; edges.len()];
+    seen[from] = true;
+    let mut stack = vec![from];
+    while let Some(node) = stack.pop() {
+        for &to in &edges[node] {
+            if !seen[to] {
+                seen[to] = true;
+                stack.push(to);
+            }
+        }
+    }
+    seen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+";
+
+    fn below(rng: &mut Rng, n: usize) -> usize {
+        rng.below(n as u64) as usize
+    }
+
+    fn big(n: u64) -> BigUint {
+        BigUint::from(n)
+    }
+
+    // Counts paths by walking every one of them, skipping nodes that
+    // cannot lead on to `to`
+    fn brute_force(
+        graph: &Graph,
+        node: NodeId,
+        to: NodeId,
+        waypoints: &[NodeId],
+        seen: &mut Vec<NodeId>,
+    ) -> u64 {
+        let leads_to = reachable(&graph.reversed(), to);
+        walk(graph, node, to, waypoints, &leads_to, seen)
+    }
+
+    fn walk(
+        graph: &Graph,
+        node: NodeId,
+        to: NodeId,
+        waypoints: &[NodeId],
+        leads_to: &[bool],
+        seen: &mut Vec<NodeId>,
+    ) -> u64 {
+        if !leads_to[node] {
+            return 0;
+        }
+        seen.push(node);
+        let mut total = 0;
+        if node == to {
+            total += u64::from(waypoints.iter().all(|w| seen.contains(w)));
+        }
+        for &next in graph.successors(node) {
+            total += walk(graph, next, to, waypoints, leads_to, seen);
+        }
+        seen.pop();
+        total
+    }
+
+    // Every path of a graph without cycles
+    fn all_paths(
+        graph: &Graph,
+        to: NodeId,
+        path: &mut Vec<NodeId>,
+        paths: &mut Vec<Vec<NodeId>>,
+    ) {
+        let node = path[path.len() - 1];
+        if node == to {
+            paths.push(path.clone());
+        }
+        for &next in graph.successors(node) {
+            path.push(next);
+            all_paths(graph, to, path, paths);
+            path.pop();
+        }
+    }
+
+    #[test]
+    fn parse_and_count() {
+        let graph = Graph::parse(11, EXAMPLE).unwrap();
+        let id = |name: &str| graph.id(name).unwrap();
+        assert_eq!(graph.len(), 14);
+        assert_eq!(graph.name(id("hub")), "hub");
+        assert_eq!(graph.successors(id("svr")), [id("aaa"), id("bbb")]);
+        assert_eq!(graph.edges().count(), 16);
+        assert_eq!(graph.count_paths(id("svr"), id("out")), Ok(big(8)));
+        assert_eq!(graph.count_paths(id("out"), id("svr")), Ok(big(0)));
+        assert_eq!(graph.count_paths(id("ccc"), id("ccc")), Ok(big(1)));
+
+        let via = |waypoints: &[&str]| {
+            let waypoints: Vec<NodeId> =
+                waypoints.iter().map(|&name| id(name)).collect();
+            graph.count_paths_via(id("svr"), id("out"), &waypoints)
+        };
+        assert_eq!(via(&["fft", "dac"]), Ok(big(2)));
+        assert_eq!(via(&["dac", "fft"]), Ok(big(2)));
+        assert_eq!(via(&["dac", "dac"]), Ok(big(4)));
+        assert_eq!(via(&["dac", "hub"]), Ok(big(0)));
+        assert_eq!(via(&[]), Ok(big(8)));
+
+        let err = Graph::parse(11, "a: b\nc d\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 11, line 2, column 1: expected `node: successors` `c d`"
+        );
+        assert!(Graph::parse(11, " : b\n").is_err());
+    }
+
+    #[test]
+    fn topo_sort_and_cycles() {
+        let graph = Graph::parse(11, EXAMPLE).unwrap();
+        let order = graph.topo_sort().unwrap();
+        let mut rank = vec![0; graph.len()];
+        for (i, &node) in order.iter().enumerate() {
+            rank[node] = i;
+        }
+        assert!(graph.edges().all(|(from, to)| rank[from] < rank[to]));
+        assert_eq!(graph.find_cycle(), None);
+
+        let graph = Graph::parse(11, "a: b\nb: c d\nc: e\nd: b\n").unwrap();
+        let cycle = graph.find_cycle().unwrap();
+        assert_eq!(graph.format_cycle(&cycle), "b -> d -> b");
+        assert_eq!(graph.count_paths(0, 4), Err(cycle));
+        // The cycle is out of reach from `c`
+        assert_eq!(graph.count_paths(2, 4), Ok(big(1)));
+        assert_eq!(
+            graph.components(),
+            [vec![0], vec![1, 3], vec![2], vec![4]]
+        );
+
+        // A loop that no path to `out` can enter changes nothing
+        let graph = Graph::parse(11, "a: b x\nx: y\ny: x\nb: out\n").unwrap();
+        let id = |name: &str| graph.id(name).unwrap();
+        let (a, out) = (id("a"), id("out"));
+        assert_eq!(graph.count_paths(a, out), Ok(big(1)));
+        assert_eq!(graph.count_paths_via(a, out, &[id("b")]), Ok(big(1)));
+        assert_eq!(graph.count_paths_via(a, out, &[id("x")]), Ok(big(0)));
+        assert_eq!(
+            graph
+                .count_paths_via(id("x"), id("y"), &[])
+                .map_err(|cycle| graph.format_cycle(&cycle)),
+            Err("x -> y -> x".to_string())
+        );
+    }
+
+    #[test]
+    fn routes() {
+        let graph = Graph::parse(11, EXAMPLE).unwrap();
+        let id = |name: &str| graph.id(name).unwrap();
+        let route = |via: &[&str], ordered, avoid: &[&str]| Route {
+            via: via.iter().map(|&name| id(name)).collect(),
+            ordered,
+            avoid: avoid.iter().map(|&name| id(name)).collect(),
+            ..Route::new(id("svr"), id("out"))
+        };
+        let count = |route: Route| graph.count_route(&route).unwrap();
+        assert_eq!(count(route(&[], false, &[])), 8);
+        assert_eq!(count(route(&["dac", "fft"], false, &[])), 2);
+        assert_eq!(count(route(&["fft", "dac"], true, &[])), 2);
+        assert_eq!(count(route(&["dac", "fft"], true, &[])), 0);
+        assert_eq!(count(route(&["fft", "dac"], false, &["ggg"])), 1);
+        assert_eq!(count(route(&[], false, &["svr"])), 0);
+        assert_eq!(count(route(&["out"], true, &["out"])), 0);
+
+        // The loop is only open to paths that skip `b`
+        let graph = Graph::parse(11, "s: a b\na: x t\nb: t\nx: a\n").unwrap();
+        let id = |name: &str| graph.id(name).unwrap();
+        let mut route = Route::new(id("s"), id("t"));
+        assert!(graph.count_route(&route).is_err());
+        route.via = vec![id("b")];
+        assert_eq!(graph.count_route(&route), Ok(big(1)));
+        route.via = vec![id("x")];
+        let Err(RouteError::Infinite(cycle)) = graph.count_route(&route)
+        else {
+            panic!("expected a cycle");
+        };
+        assert_eq!(graph.format_cycle(&cycle), "a -> x -> a");
+        route.via.clear();
+        route.avoid = vec![id("x")];
+        assert_eq!(graph.count_route(&route), Ok(big(2)));
+
+        // Counting takes any number of waypoints, listing a bounded number
+        let mut graph = Graph::new();
+        for node in 0..=MAX_UNORDERED + 1 {
+            graph.add_edge(&node.to_string(), &(node + 1).to_string());
+        }
+        let mut route = Route::new(0, graph.len() - 1);
+        route.via = (1..=MAX_UNORDERED + 1).collect();
+        assert_eq!(graph.count_route(&route), Ok(big(1)));
+        assert_eq!(
+            graph.route_paths(&route).err(),
+            Some(RouteError::TooManyWaypoints(MAX_UNORDERED + 1))
+        );
+        route.via.pop();
+        assert_eq!(graph.route_paths(&route).map(|p| p.count()), Ok(big(1)));
+    }
+
+    #[test]
+    fn list_and_sample() {
+        let graph = Graph::parse(11, EXAMPLE).unwrap();
+        let id = |name: &str| graph.id(name).unwrap();
+        let mut route = Route::new(id("svr"), id("out"));
+        route.via = vec![id("hub")];
+        let paths = graph.route_paths(&route).unwrap();
+        let listed: Vec<String> =
+            paths.iter().map(|path| graph.format_path(&path)).collect();
+        assert_eq!(
+            listed,
+            [
+                "svr -> aaa -> fft -> ccc -> ddd -> hub -> fff -> ggg -> out",
+                "svr -> aaa -> fft -> ccc -> ddd -> hub -> fff -> hhh -> out",
+                "svr -> bbb -> tty -> ccc -> ddd -> hub -> fff -> ggg -> out",
+                "svr -> bbb -> tty -> ccc -> ddd -> hub -> fff -> hhh -> out",
+            ]
+        );
+
+        // Every one of the 8 paths turns up about as often
+        let paths = graph.route_paths(&Route::new(id("svr"), id("out")));
+        let paths = paths.unwrap();
+        let mut rng = Rng::new(11);
+        let mut seen = HashMap::new();
+        for _ in 0..8000 {
+            *seen.entry(paths.sample(&mut rng).unwrap()).or_insert(0) += 1;
+        }
+        assert_eq!(seen.len(), 8);
+        let visits = paths.visits();
+        assert_eq!(visits[id("svr")], 8);
+        assert_eq!(visits[id("fft")], 4);
+        assert_eq!(visits[id("hhh")], 4);
+        assert!(seen.values().all(|&n| (800..1200).contains(&n)));
+        assert!(seen.keys().all(|path| paths.iter().any(|p| &p == path)));
+
+        let route = Route::new(id("out"), id("svr"));
+        let paths = graph.route_paths(&route).unwrap();
+        assert_eq!(paths.count(), 0);
+        assert_eq!(paths.iter().next(), None);
+        assert_eq!(paths.sample(&mut rng), None);
+        let route = Route::new(id("ccc"), id("ccc"));
+        let paths = graph.route_paths(&route).unwrap();
+        assert_eq!(paths.iter().collect::<Vec<_>>(), [vec![id("ccc")]]);
+        assert_eq!(paths.shortest(5), [vec![id("ccc")]]);
+    }
+
+    #[test]
+    fn long_chain() {
+        // Deep enough to overflow the stack if the searches recursed
+        let n = 200_000;
+        let mut graph = Graph::new();
+        for node in 0..n {
+            graph.add_node(&node.to_string());
+        }
+        for node in 1..n {
+            graph.edges[node - 1].push(node);
+        }
+        assert_eq!(graph.count_paths(0, n - 1), Ok(big(1)));
+        assert_eq!(graph.count_paths_via(0, n - 1, &[n / 2]), Ok(big(1)));
+        assert_eq!(graph.topo_sort().map(|order| order.len()), Ok(n));
+        graph.edges[n - 1].push(0);
+        assert_eq!(graph.find_cycle().map(|Cycle(c)| c.len()), Some(n));
+        assert_eq!(graph.components().len(), 1);
+    }
+
+    #[test]
+    fn past_u64() {
+        // 100 diamonds in a row, each doubling the paths
+        let mut graph = Graph::new();
+        for i in 0..100 {
+            for side in ["l", "r"] {
+                let side = format!("{side}{i}");
+                graph.add_edge(&i.to_string(), &side);
+                graph.add_edge(&side, &(i + 1).to_string());
+            }
+        }
+        let (from, to) = (graph.id("0").unwrap(), graph.id("100").unwrap());
+        let two_100 = "1267650600228229401496703205376";
+        let count = graph.count_paths(from, to).unwrap();
+        assert_eq!(count.to_string(), two_100);
+        let via = [graph.id("l50").unwrap()];
+        let half = graph.count_paths_via(from, to, &via).unwrap();
+        assert_eq!(&half + &half, count);
+
+        let paths = graph.route_paths(&Route::new(from, to)).unwrap();
+        assert_eq!(paths.count(), count);
+        assert_eq!(paths.visits()[from], count);
+        assert_eq!(paths.visits()[via[0]], half);
+        let mut rng = Rng::new(100);
+        assert_eq!(paths.sample(&mut rng).map(|path| path.len()), Some(201));
+        assert_eq!(paths.shortest(1)[0].len(), 201);
+    }
+
+    #[test]
+    fn cycles_match_closure() {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for round in 0..200 {
+            let n = 2 + round % 8;
+            let mut graph = Graph::new();
+            for node in 0..n {
+                graph.add_node(&node.to_string());
+            }
+            for _ in 0..n + below(&mut rng, n) {
+                let (a, b) = (below(&mut rng, n), below(&mut rng, n));
+                graph.add_edge(&a.to_string(), &b.to_string());
+            }
+
+            // reach[a][b]: a path of at least one edge leads from a to b
+            let mut reach = vec![vec![false; n]; n];
+            for (a, b) in graph.edges() {
+                reach[a][b] = true;
+            }
+            for k in 0..n {
+                for a in 0..n {
+                    for b in 0..n {
+                        reach[a][b] |= reach[a][k] && reach[k][b];
+                    }
+                }
+            }
+
+            let (from, to) = (below(&mut rng, n), below(&mut rng, n));
+            let infinite = (0..n).any(|c| {
+                reach[c][c]
+                    && (c == from || reach[from][c])
+                    && (c == to || reach[c][to])
+            });
+            let counted = graph.count_paths(from, to);
+            assert_eq!(counted.is_err(), infinite);
+            let routed = graph.count_route(&Route::new(from, to));
+            assert_eq!(routed.is_err(), infinite);
+            assert_eq!(routed.ok(), counted.as_ref().ok().cloned());
+            match counted {
+                Ok(count) => assert_eq!(
+                    count,
+                    brute_force(&graph, from, to, &[], &mut Vec::new())
+                ),
+                // The reported cycle is real and on a path
+                Err(Cycle(cycle)) => {
+                    for (i, &node) in cycle.iter().enumerate() {
+                        let next = cycle[(i + 1) % cycle.len()];
+                        assert!(graph.successors(node).contains(&next));
+                    }
+                    let c = cycle[0];
+                    assert!(c == from || reach[from][c]);
+                    assert!(c == to || reach[c][to]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for round in 0..100 {
+            // Edges only run from lower to higher names, so no cycles
+            let n = 2 + round % 10;
+            let mut graph = Graph::new();
+            for node in 0..n {
+                graph.add_node(&node.to_string());
+            }
+            for _ in 0..2 * n {
+                let (a, b) = (below(&mut rng, n), below(&mut rng, n));
+                if a != b {
+                    let (a, b) = (a.min(b), a.max(b));
+                    graph.add_edge(&a.to_string(), &b.to_string());
+                }
+            }
+
+            let (from, to) = (below(&mut rng, n), below(&mut rng, n));
+            let waypoints: Vec<NodeId> = (0..below(&mut rng, 4))
+                .map(|_| below(&mut rng, n))
+                .collect();
+            let expected =
+                brute_force(&graph, from, to, &waypoints, &mut Vec::new());
+            assert_eq!(
+                graph.count_paths_via(from, to, &waypoints),
+                Ok(big(expected))
+            );
+            let all = brute_force(&graph, from, to, &[], &mut Vec::new());
+            assert_eq!(graph.count_paths(from, to), Ok(big(all)));
+
+            let mut route = Route::new(from, to);
+            route.via = waypoints.clone();
+            assert_eq!(graph.count_route(&route), Ok(big(expected)));
+            let found = graph.route_paths(&route).unwrap();
+            assert_eq!(found.count(), big(expected));
+
+            // Listed in order of names, then of length and names
+            let mut paths = Vec::new();
+            all_paths(&graph, to, &mut vec![from], &mut paths);
+            paths.retain(|path| waypoints.iter().all(|w| path.contains(w)));
+            let names = |path: &Vec<NodeId>| -> Vec<&str> {
+                path.iter().map(|&node| graph.name(node)).collect()
+            };
+            paths.sort_by_key(names);
+            assert_eq!(found.iter().collect::<Vec<_>>(), paths);
+            paths.sort_by_key(|path| (path.len(), names(path)));
+            paths.truncate(3);
+            assert_eq!(found.shortest(3), paths);
+            // Without cycles each node is met once, so the waypoints must
+            // appear in the path as a subsequence
+            route.ordered = true;
+            route.avoid = vec![below(&mut rng, n)];
+            let mut paths = Vec::new();
+            all_paths(&graph, to, &mut vec![from], &mut paths);
+            let ordered = paths.iter().filter(|path| {
+                let mut rest = path.iter();
+                !path.contains(&route.avoid[0])
+                    && waypoints.iter().all(|w| rest.any(|n| n == w))
+            });
+            let expected = ordered.count() as u64;
+            if waypoints.windows(2).all(|w| w[0] != w[1]) {
+                assert_eq!(graph.count_route(&route), Ok(big(expected)));
+            }
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::expect_chars;
+use crate::ParseError;
+
+/// A cell position as `(row, col)`, counted from the top left.
+pub type Pos = (usize, usize);
+
+// Offsets to the 4 orthogonal neighbours, then the 4 diagonal ones
+const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, 0),
+    (0, 1),
+    (1, 0),
+    (0, -1),
+    (-1, -1),
+    (-1, 1),
+    (1, 1),
+    (1, -1),
+];
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, or returns the index of the first row
+    /// whose length differs from the first one's.
+    pub fn from_rows<R>(
+        rows: impl IntoIterator<Item = R>,
+    ) -> Result<Self, usize>
+    where
+        R: IntoIterator<Item = T>,
+    {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        for row in rows {
+            let before = cells.len();
+            cells.extend(row);
+            let len = cells.len() - before;
+            if height == 0 {
+                width = len;
+            } else if len != width {
+                return Err(height);
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        if row < self.height && col < self.width {
+            self.cells.get(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        if row < self.height && col < self.width {
+            self.cells.get_mut(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    /// The position at signed coordinates, if it lies inside the grid.
+    pub fn checked_pos(&self, row: isize, col: isize) -> Option<Pos> {
+        let (row, col) =
+            (usize::try_from(row).ok()?, usize::try_from(col).ok()?);
+        (row < self.height && col < self.width).then_some((row, col))
+    }
+
+    /// The orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offsets(pos, &DIRECTIONS[..4])
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offsets(pos, &DIRECTIONS)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        (row, col): Pos,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        offsets.iter().filter_map(move |&(dr, dc)| {
+            let row = row.checked_add_signed(dr)?;
+            let col = col.checked_add_signed(dc)?;
+            (row < self.height && col < self.width).then_some((row, col))
+        })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {col} out of bounds");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height)
+            .flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The first position holding `value`, in row-major order.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        let index = self.cells.iter().position(|cell| cell == value)?;
+        Some((index / self.width, index % self.width))
+    }
+
+    /// Every position holding `value`, in row-major order.
+    pub fn find_all<'a>(
+        &'a self,
+        value: &'a T,
+    ) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        let width = self.width;
+        (self.cells.iter().enumerate())
+            .filter(move |&(_, cell)| cell == value)
+            .map(move |(index, _)| (index / width, index % width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, col)| (col, row))
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last = self.height.saturating_sub(1);
+        self.rebuild(self.height, self.width, |(row, col)| (last - col, row))
+    }
+
+    /// Rotates a quarter turn anticlockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last = self.width.saturating_sub(1);
+        self.rebuild(self.height, self.width, |(row, col)| (col, last - row))
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last = self.width.saturating_sub(1);
+        self.rebuild(self.width, self.height, |(row, col)| (row, last - col))
+    }
+
+    // A `width` by `height` grid whose cell at each position is the one at
+    // `source(pos)` in this grid
+    fn rebuild(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(Pos) -> Pos,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses the non-empty lines of `input` as rows of characters, each
+    /// one of `allowed`.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        allowed: &str,
+    ) -> Result<Self, ParseError> {
+        let lines: Vec<&str> =
+            input.lines().filter(|line| !line.is_empty()).collect();
+        for line in &lines {
+            expect_chars(day, input, line, allowed)?;
+        }
+        let rows = lines.iter().map(|line| line.chars());
+        Self::from_rows(rows).map_err(|row| {
+            let message = "rows differ in length";
+            ParseError::locate(day, input, lines[row], message)
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("position {pos:?} out of bounds"),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("position {pos:?} out of bounds"),
+        }
+    }
+}
+
+/// Renders each row on its own line, cells written back to back.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(4, "abc\ndef\n", "abcdef").unwrap()
+    }
+
+    #[test]
+    fn parse_and_access() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.checked_pos(-1, 0), None);
+        assert_eq!(grid.checked_pos(1, 1), Some((1, 1)));
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let err = Grid::parse(4, "ab\nabc\n", "abc").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 4, line 2, column 1: rows differ in length `abc`"
+        );
+        assert!(Grid::parse(4, "ax\n", "abc").is_err());
+        assert_eq!(Grid::parse(4, "", "abc").unwrap().to_string(), "");
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        let mut four: Vec<Pos> = grid.neighbours4((0, 0)).collect();
+        four.sort();
+        assert_eq!(four, [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours4((2, 1)).count(), 3);
+    }
+
+    #[test]
+    fn transform() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        let full_turn = (0..4).fold(grid.clone(), |g, _| g.rotate_cw());
+        assert_eq!(full_turn, grid);
+        assert_eq!(grid.find_all(&'a').collect::<Vec<_>>(), [(0, 0)]);
+        assert_eq!(grid.map(|&c| c == 'e').find(&true), Some((1, 1)));
+    }
+}
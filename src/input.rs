@@ -0,0 +1,344 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::answers::parse_expected;
+use crate::cache::{self, InputStatus, Manifest, MANIFEST_FILE};
+use crate::{Answer, Error, Part, Result};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `{dir}/{year}/{day:02}-{name}.txt`, e.g. `inputs/2025/03-example.txt`.
+    Named(String),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses a command-line path, where `-` means standard input.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+}
+
+/// An example input from the puzzle text, with whatever answers it is
+/// known to produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// File name without the day prefix, e.g. `example2`.
+    pub name: String,
+    pub input: String,
+    /// Read from `NN-<name>.expected`; empty when there is no such file.
+    pub expected: BTreeMap<Part, Answer>,
+}
+
+impl Example {
+    /// Whether this example is meant for `part`. An example with expected
+    /// answers only covers the parts listed, so a day can have a separate
+    /// example per part.
+    pub fn applies_to(&self, part: Part) -> bool {
+        self.expected.is_empty() || self.expected.contains_key(&part)
+    }
+}
+
+/// Resolves and reads the puzzle inputs of one year.
+///
+/// Files are looked up in the year's subdirectory of the input directory
+/// unless a day has an explicit [`InputSource`] override.
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    root: PathBuf,
+    year: u16,
+    overrides: BTreeMap<u8, InputSource>,
+}
+
+impl Default for Inputs {
+    /// Uses `$AOC_INPUT_DIR`, falling back to `inputs`, and the latest
+    /// year with a registered puzzle.
+    fn default() -> Self {
+        let dir = env::var_os(INPUT_DIR_VAR)
+            .filter(|dir| !dir.is_empty())
+            .map_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR), PathBuf::from);
+        Self::new(dir)
+    }
+}
+
+impl Inputs {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            year: crate::latest_year(),
+            overrides: BTreeMap::new(),
+        }
+    }
+
+    /// The default inputs, but for `year`.
+    pub fn for_year(year: u16) -> Self {
+        let mut inputs = Self::default();
+        inputs.set_year(year);
+        inputs
+    }
+
+    /// The directory holding this year's files, e.g. `inputs/2025`.
+    pub fn dir(&self) -> PathBuf {
+        self.root.join(self.year.to_string())
+    }
+
+    /// Sets the input directory, under which each year has its own.
+    pub fn set_dir(&mut self, root: impl Into<PathBuf>) {
+        self.root = root.into();
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn set_year(&mut self, year: u16) {
+        self.year = year;
+    }
+
+    /// Reads `day` from `source` whatever name is asked for.
+    pub fn set_override(&mut self, day: u8, source: InputSource) {
+        self.overrides.insert(day, source);
+    }
+
+    pub fn is_overridden(&self, day: u8) -> bool {
+        self.overrides.contains_key(&day)
+    }
+
+    pub fn path(&self, day: u8, name: &str) -> PathBuf {
+        self.dir().join(format!("{day:02}-{name}.txt"))
+    }
+
+    pub fn source(&self, day: u8, name: &str) -> InputSource {
+        self.overrides
+            .get(&day)
+            .cloned()
+            .unwrap_or_else(|| InputSource::Named(name.to_string()))
+    }
+
+    /// Reads input `name` (e.g. `input` or `example`) for `day`. The real
+    /// input is checked against the manifest first, see [`Inputs::status`].
+    pub fn load(&self, day: u8, name: &str) -> Result<String> {
+        let source = self.source(day, name);
+        let text = self.read(day, &source)?;
+        if source == InputSource::Named("input".to_string()) {
+            if let InputStatus::Suspicious(problem) =
+                self.inspect(day, &text)?
+            {
+                let path = self.path(day, name);
+                return Err(Error::BadInput { path, problem });
+            }
+        }
+        Ok(text)
+    }
+
+    /// Saves a freshly fetched real input and records its checksum.
+    pub fn store(&self, day: u8, text: &str) -> Result<PathBuf> {
+        let path = self.path(day, "input");
+        let text = cache::normalise(text);
+        if let Err(problem) = cache::inspect(&text, None) {
+            return Err(Error::BadInput { path, problem });
+        }
+        let dir = self.dir();
+        fs::create_dir_all(&dir)
+            .and_then(|()| fs::write(&path, &text))
+            .map_err(|source| Error::Io {
+                path: path.clone(),
+                source,
+            })?;
+
+        let manifest_path = dir.join(MANIFEST_FILE);
+        let mut manifest = Manifest::load(&manifest_path)?;
+        let fetched = cache::Fetched {
+            checksum: cache::checksum(&text),
+            len: text.len(),
+            time: SystemTime::now(),
+        };
+        manifest.set(day, "input", fetched);
+        manifest.save(&manifest_path)?;
+        Ok(path)
+    }
+
+    /// Whether the real input for `day` is present and intact.
+    pub fn status(&self, day: u8) -> Result<InputStatus> {
+        let path = self.path(day, "input");
+        match fs::read_to_string(&path) {
+            Ok(text) => self.inspect(day, &cache::normalise(&text)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                Ok(InputStatus::Missing)
+            }
+            Err(source) => Err(Error::Io { path, source }),
+        }
+    }
+
+    fn inspect(&self, day: u8, text: &str) -> Result<InputStatus> {
+        let manifest = Manifest::load(self.dir().join(MANIFEST_FILE))?;
+        let fetched = manifest.get(day, "input");
+        Ok(match (cache::inspect(text, fetched), fetched) {
+            (Err(problem), _) => InputStatus::Suspicious(problem),
+            (Ok(()), Some(fetched)) => InputStatus::Fetched(*fetched),
+            (Ok(()), None) => InputStatus::Unrecorded,
+        })
+    }
+
+    /// Finds every `NN-example*.txt` for `day`, ordered by name, along with
+    /// the `.expected` file beside each.
+    pub fn examples(&self, day: u8) -> Result<Vec<Example>> {
+        let dir = self.dir();
+        let io_error = |source| Error::Io {
+            path: dir.clone(),
+            source,
+        };
+        let prefix = format!("{day:02}-");
+        let mut names = Vec::new();
+        for entry in fs::read_dir(&dir).map_err(io_error)? {
+            let file_name = entry.map_err(io_error)?.file_name();
+            let name = file_name
+                .to_str()
+                .and_then(|name| name.strip_prefix(&prefix))
+                .and_then(|name| name.strip_suffix(".txt"))
+                .filter(|name| name.starts_with("example"));
+            if let Some(name) = name {
+                names.push(name.to_string());
+            }
+        }
+        if names.is_empty() {
+            // Report the file that would have been read before discovery
+            read_file(&self.path(day, "example"))?;
+        }
+        names.sort();
+
+        let mut examples = Vec::new();
+        for name in names {
+            let path = dir.join(format!("{prefix}{name}.expected"));
+            let expected = match fs::read_to_string(&path) {
+                Ok(text) => parse_expected(&text, &path)?,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    BTreeMap::new()
+                }
+                Err(source) => return Err(Error::Io { path, source }),
+            };
+            let input = cache::normalise(&read_file(&self.path(day, &name))?);
+            examples.push(Example {
+                name,
+                input,
+                expected,
+            });
+        }
+        Ok(examples)
+    }
+
+    /// The examples that apply to `part` of `day`.
+    pub fn examples_for(&self, day: u8, part: Part) -> Result<Vec<Example>> {
+        let mut examples = self.examples(day)?;
+        examples.retain(|example| example.applies_to(part));
+        Ok(examples)
+    }
+
+    /// Reads `source` with its line endings normalised.
+    pub fn read(&self, day: u8, source: &InputSource) -> Result<String> {
+        let text = match source {
+            InputSource::Named(name) => read_file(&self.path(day, name))?,
+            InputSource::File(path) => read_file(path)?,
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map_err(|source| {
+                    Error::Io {
+                        path: PathBuf::from("<stdin>"),
+                        source,
+                    }
+                })?;
+                input
+            }
+        };
+        Ok(cache::normalise(&text))
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve() {
+        let mut inputs = Inputs::new("data");
+        inputs.set_year(2025);
+        assert_eq!(
+            inputs.path(3, "example"),
+            Path::new("data/2025/03-example.txt")
+        );
+        assert_eq!(
+            inputs.source(3, "input"),
+            InputSource::Named("input".into())
+        );
+
+        inputs.set_override(3, InputSource::from_arg("-"));
+        assert_eq!(inputs.source(3, "input"), InputSource::Stdin);
+        assert!(inputs.is_overridden(3));
+        assert!(!inputs.is_overridden(4));
+    }
+
+    #[test]
+    fn examples() {
+        let mut inputs = Inputs::new(DEFAULT_INPUT_DIR);
+        inputs.set_year(2025);
+        let examples = inputs.examples(11).unwrap();
+        let names: Vec<&str> =
+            examples.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["example", "example2"]);
+
+        let part_two = inputs.examples_for(11, Part::Two).unwrap();
+        assert_eq!(part_two.len(), 1);
+        assert_eq!(part_two[0].name, "example2");
+        assert_eq!(
+            part_two[0].expected.get(&Part::Two),
+            Some(&Answer::Int(2))
+        );
+    }
+
+    #[test]
+    fn integrity() {
+        let dir = env::temp_dir().join(format!("aoc-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let inputs = Inputs::new(&dir);
+
+        let path = inputs.store(5, "3-5\r\n\r\n1\r\n\r\n").unwrap();
+        assert_eq!(inputs.load(5, "input").unwrap(), "3-5\n\n1\n");
+        assert!(matches!(inputs.status(5), Ok(InputStatus::Fetched(_))));
+
+        fs::write(&path, "3-").unwrap();
+        let err = inputs.load(5, "input").unwrap_err();
+        assert!(err.to_string().ends_with("truncated, 3 of 7 bytes"));
+        assert!(inputs.store(5, "<!DOCTYPE html>").is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn missing_file() {
+        let mut inputs = Inputs::new("no-such-dir");
+        inputs.set_year(2025);
+        let err = inputs.load(1, "input").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("no-such-dir/2025/01-input.txt: "));
+    }
+}
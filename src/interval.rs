@@ -0,0 +1,322 @@
+use std::fmt;
+
+/// Integer types an [`IntervalSet`] can hold.
+pub trait Integer: Copy + Ord + fmt::Debug {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_succ(self) -> Option<Self>;
+    fn checked_pred(self) -> Option<Self>;
+
+    /// How many values `start..=end` holds, for `start <= end`.
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! integer {
+    ($($t:ty)*) => {$(
+        impl Integer for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn checked_succ(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn checked_pred(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count(start: Self, end: Self) -> u128 {
+                (end as i128 - start as i128) as u128 + 1
+            }
+        }
+    )*};
+}
+
+integer!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+
+/// A set of integers stored as sorted, disjoint inclusive intervals.
+///
+/// Overlapping and adjacent intervals are merged as they are added, so
+/// `1..=3` and `4..=6` become `1..=6`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `start..=end`; an empty interval is ignored.
+    pub fn insert(&mut self, start: T, end: T) {
+        if start > end {
+            return;
+        }
+        // Intervals from `first` to `last` overlap or touch the new one
+        let first = self.intervals.partition_point(|&(_, e)| {
+            e.checked_succ().is_some_and(|next| next < start)
+        });
+        let last = self.intervals.partition_point(|&(s, _)| {
+            end.checked_succ().is_none_or(|next| s <= next)
+        });
+        let (mut start, mut end) = (start, end);
+        if first < last {
+            start = start.min(self.intervals[first].0);
+            end = end.max(self.intervals[last - 1].1);
+        }
+        self.intervals.splice(first..last, [(start, end)]);
+    }
+
+    /// Removes `start..=end`, splitting any interval it falls inside.
+    pub fn remove(&mut self, start: T, end: T) {
+        if start > end {
+            return;
+        }
+        let first = self.intervals.partition_point(|&(_, e)| e < start);
+        let last = self.intervals.partition_point(|&(s, _)| s <= end);
+        if first == last {
+            return;
+        }
+
+        let mut kept = Vec::new();
+        let (left, _) = self.intervals[first];
+        if left < start {
+            kept.extend(start.checked_pred().map(|pred| (left, pred)));
+        }
+        let (_, right) = self.intervals[last - 1];
+        if right > end {
+            kept.extend(end.checked_succ().map(|succ| (succ, right)));
+        }
+        self.intervals.splice(first..last, kept);
+    }
+
+    /// Whether `value` is in the set, by binary search.
+    pub fn contains(&self, value: T) -> bool {
+        let after = self.intervals.partition_point(|&(s, _)| s <= value);
+        after > 0 && self.intervals[after - 1].1 >= value
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(|&(s, e)| T::count(s, e)).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The intervals as `(start, end)`, inclusive and in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = (T, T)> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.iter().chain(other.iter()).collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut a, mut b) =
+            (self.iter().peekable(), other.iter().peekable());
+        let mut intervals = Vec::new();
+        while let (Some(&(s1, e1)), Some(&(s2, e2))) = (a.peek(), b.peek()) {
+            let (start, end) = (s1.max(s2), e1.min(e2));
+            if start <= end {
+                intervals.push((start, end));
+            }
+            // The interval ending first cannot meet anything further on
+            if e1 < e2 {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement(T::MIN, T::MAX))
+    }
+
+    /// Everything in `lo..=hi` that is not in the set.
+    pub fn complement(&self, lo: T, hi: T) -> Self {
+        let mut intervals = Vec::new();
+        // The smallest value not yet covered or known to be in the set
+        let mut next = Some(lo);
+        for &(start, end) in &self.intervals {
+            let Some(from) = next else { break };
+            if start > hi {
+                break;
+            }
+            if end < from {
+                continue;
+            }
+            if start > from {
+                // `start > from` so it has a predecessor
+                intervals
+                    .extend(start.checked_pred().map(|pred| (from, pred)));
+            }
+            next = end.checked_succ();
+        }
+        if let Some(from) = next.filter(|&from| from <= hi) {
+            intervals.push((from, hi));
+        }
+        Self { intervals }
+    }
+}
+
+impl<T: Integer> FromIterator<(T, T)> for IntervalSet<T> {
+    /// Collects `(start, end)` pairs, sorting and merging them in one go;
+    /// as with [`IntervalSet::insert`], empty intervals are ignored.
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self {
+        let mut sorted: Vec<(T, T)> =
+            iter.into_iter().filter(|&(s, e)| s <= e).collect();
+        sorted.sort_unstable();
+
+        let mut intervals: Vec<(T, T)> = Vec::with_capacity(sorted.len());
+        for (start, end) in sorted {
+            match intervals.last_mut() {
+                Some(last)
+                    if last.1.checked_succ().is_none_or(|n| start <= n) =>
+                {
+                    last.1 = last.1.max(end);
+                }
+                _ => intervals.push((start, end)),
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl<T: Integer> Extend<(T, T)> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = (T, T)>>(&mut self, iter: I) {
+        for (start, end) in iter {
+            self.insert(start, end);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals.iter().copied().collect()
+    }
+
+    fn intervals(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn insert_merges() {
+        let mut s = IntervalSet::new();
+        s.insert(10, 14);
+        s.insert(1, 3);
+        s.insert(5, 6);
+        assert_eq!(intervals(&s), [(1, 3), (5, 6), (10, 14)]);
+        s.insert(4, 4);
+        assert_eq!(intervals(&s), [(1, 6), (10, 14)]);
+        s.insert(8, 20);
+        assert_eq!(intervals(&s), [(1, 6), (8, 20)]);
+        s.insert(3, 2);
+        assert_eq!(s.len(), 19);
+        assert_eq!(s, set(&[(8, 20), (1, 3), (4, 6)]));
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut s = set(&[(1, 10), (20, 30)]);
+        s.remove(4, 6);
+        assert_eq!(intervals(&s), [(1, 3), (7, 10), (20, 30)]);
+        s.remove(9, 25);
+        assert_eq!(intervals(&s), [(1, 3), (7, 8), (26, 30)]);
+        s.remove(0, 100);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn membership() {
+        let s = set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+        let members: Vec<i32> = (0..25).filter(|&x| s.contains(x)).collect();
+        assert_eq!(
+            members,
+            [3, 4, 5, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20]
+        );
+        assert_eq!(s.len(), 14);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (20, 21)]);
+        assert_eq!(intervals(&a.union(&b)), [(1, 15), (20, 21)]);
+        assert_eq!(intervals(&a.intersection(&b)), [(4, 5), (10, 11)]);
+        assert_eq!(intervals(&a.difference(&b)), [(1, 3), (12, 15)]);
+        assert_eq!(intervals(&a.complement(0, 12)), [(0, 0), (6, 9)]);
+        assert_eq!(intervals(&a.complement(6, 9)), [(6, 9)]);
+        assert!(a.complement(2, 4).is_empty());
+    }
+
+    #[test]
+    fn extremes() {
+        let mut s: IntervalSet<u8> =
+            [(0, 10), (250, 255)].into_iter().collect();
+        assert_eq!(s.len(), 17);
+        assert_eq!(
+            s.complement(u8::MIN, u8::MAX).iter().collect::<Vec<_>>(),
+            [(11, 249)]
+        );
+        s.insert(11, 249);
+        assert_eq!(s.len(), 256);
+        assert!(s.complement(0, 255).is_empty());
+        s.remove(0, 0);
+        s.remove(255, 255);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [(1, 254)]);
+    }
+
+    #[test]
+    fn matches_bitset() {
+        // Every pair of small sets built from a few intervals each
+        let pieces = [(0, 2), (1, 4), (6, 6), (5, 9), (8, 11)];
+        let subsets = |mask: usize| {
+            let picked = pieces
+                .iter()
+                .enumerate()
+                .filter(move |(i, _)| mask >> i & 1 == 1);
+            picked.map(|(_, &p)| p).collect::<IntervalSet<i32>>()
+        };
+        let bits = |s: &IntervalSet<i32>| {
+            (0..12)
+                .filter(|&x| s.contains(x))
+                .fold(0u32, |b, x| b | 1 << x)
+        };
+        for m in 0..32 {
+            for n in 0..32 {
+                let (a, b) = (subsets(m), subsets(n));
+                assert_eq!(bits(&a.union(&b)), bits(&a) | bits(&b));
+                assert_eq!(bits(&a.intersection(&b)), bits(&a) & bits(&b));
+                assert_eq!(bits(&a.difference(&b)), bits(&a) & !bits(&b));
+                assert_eq!(bits(&a.complement(0, 11)), !bits(&a) & 0xfff);
+                assert_eq!(a.len(), u128::from(bits(&a).count_ones()));
+
+                let mut c = a.clone();
+                c.extend(b.iter());
+                assert_eq!(c, a.union(&b));
+                for (start, end) in b.iter() {
+                    c.remove(start, end);
+                }
+                assert_eq!(c, a.difference(&b));
+            }
+        }
+    }
+}
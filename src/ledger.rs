@@ -0,0 +1,292 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::answers::{quote_text, unquote_text};
+use crate::{Answer, Error, Part, Result, Submission};
+
+pub const LEDGER_FILE: &str = "submissions.txt";
+
+/// One answer sent to the server and what it said about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub outcome: Submission,
+}
+
+/// Why an answer is not worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was already solved with this answer.
+    Solved { answer: Answer },
+    /// Exactly this answer was rejected before.
+    KnownWrong { outcome: Submission },
+    /// At least as high as an answer reported too high.
+    TooHigh { bound: Answer },
+    /// At most as low as an answer reported too low.
+    TooLow { bound: Answer },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::Solved { answer } => {
+                write!(f, "already solved with {answer}")
+            }
+            Refusal::KnownWrong { outcome } => {
+                write!(f, "already submitted, {outcome}")
+            }
+            Refusal::TooHigh { bound } => {
+                write!(f, "must be below {bound}, which was too high")
+            }
+            Refusal::TooLow { bound } => {
+                write!(f, "must be above {bound}, which was too low")
+            }
+        }
+    }
+}
+
+/// Every submission made so far, one per line as `day part outcome answer`
+/// in `submissions.txt` beside the inputs. Text answers are quoted, so
+/// they cannot be mistaken for numbers or spill onto another line.
+///
+/// Rate-limited attempts are not recorded, since they say nothing about the
+/// answer.
+#[derive(Debug, Clone, Default)]
+pub struct Ledger {
+    entries: Vec<Entry>,
+}
+
+impl Ledger {
+    /// Loads the ledger; a missing file gives an empty one.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text, path),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(source) => Err(Error::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        fs::write(path, self.to_string()).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn parse(text: &str, path: &Path) -> Result<Self> {
+        let mut ledger = Self::default();
+        for (n, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry = parse_entry(line).ok_or_else(|| Error::Invalid {
+                path: PathBuf::from(path),
+                line: n + 1,
+                message: "expected `day part outcome answer`".into(),
+            })?;
+            ledger.entries.push(entry);
+        }
+        Ok(ledger)
+    }
+
+    pub fn entries(
+        &self,
+        day: u8,
+        part: Part,
+    ) -> impl Iterator<Item = &Entry> {
+        self.entries
+            .iter()
+            .filter(move |e| e.day == day && e.part == part)
+    }
+
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+        outcome: Submission,
+    ) {
+        if outcome_key(outcome).is_some() {
+            self.entries.push(Entry {
+                day,
+                part,
+                answer: answer.clone(),
+                outcome,
+            });
+        }
+    }
+
+    /// Checks `answer` against what earlier submissions revealed. Only
+    /// integers are held to the bounds of too high and too low answers.
+    pub fn check(
+        &self,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<(), Refusal> {
+        for entry in self.entries(day, part) {
+            if entry.outcome == Submission::Correct {
+                let answer = entry.answer.clone();
+                return Err(Refusal::Solved { answer });
+            }
+            if entry.answer == *answer && entry.outcome.is_wrong() {
+                let outcome = entry.outcome;
+                return Err(Refusal::KnownWrong { outcome });
+            }
+
+            let bound = entry.answer.clone();
+            match entry.outcome {
+                Submission::TooHigh if *answer >= bound => {
+                    return Err(Refusal::TooHigh { bound });
+                }
+                Submission::TooLow if *answer <= bound => {
+                    return Err(Refusal::TooLow { bound });
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Ledger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            // Only recordable outcomes make it into the ledger
+            let outcome = outcome_key(entry.outcome).unwrap_or("unknown");
+            let part = entry.part.number();
+            let answer = match &entry.answer {
+                Answer::Text(text) => quote_text(text),
+                answer => answer.to_string(),
+            };
+            writeln!(f, "{} {part} {outcome} {answer}", entry.day)?;
+        }
+        Ok(())
+    }
+}
+
+fn outcome_key(outcome: Submission) -> Option<&'static str> {
+    match outcome {
+        Submission::Correct => Some("correct"),
+        Submission::TooHigh => Some("too_high"),
+        Submission::TooLow => Some("too_low"),
+        Submission::Wrong => Some("wrong"),
+        Submission::AlreadySolved => Some("already_solved"),
+        Submission::RateLimited { .. } => None,
+    }
+}
+
+fn parse_entry(line: &str) -> Option<Entry> {
+    let mut fields = line.splitn(4, ' ');
+    let day = fields.next()?.parse().ok()?;
+    let part = Part::from_number(fields.next()?.parse().ok()?)?;
+    let outcome = match fields.next()? {
+        "correct" => Submission::Correct,
+        "too_high" => Submission::TooHigh,
+        "too_low" => Submission::TooLow,
+        "wrong" => Submission::Wrong,
+        "already_solved" => Submission::AlreadySolved,
+        _ => return None,
+    };
+    // Text is always quoted, so anything else must be an integer
+    let answer = match fields.next()? {
+        quoted if quoted.starts_with('"') => {
+            Answer::Text(unquote_text(quoted)?)
+        }
+        bare => Some(Answer::parse(bare)).filter(Answer::is_integer)?,
+    };
+    Some(Entry {
+        day,
+        part,
+        answer,
+        outcome,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds() {
+        let mut ledger = Ledger::default();
+        ledger.record(1, Part::One, &Answer::Int(100), Submission::TooHigh);
+        ledger.record(1, Part::One, &Answer::Int(40), Submission::TooLow);
+        ledger.record(1, Part::One, &Answer::Int(50), Submission::Wrong);
+        ledger.record(
+            1,
+            Part::One,
+            &Answer::Int(60),
+            Submission::RateLimited { wait: None },
+        );
+
+        assert_eq!(ledger.check(1, Part::One, &Answer::Int(60)), Ok(()));
+        assert_eq!(
+            ledger.check(1, Part::One, &Answer::Int(50)),
+            Err(Refusal::KnownWrong {
+                outcome: Submission::Wrong
+            })
+        );
+        assert_eq!(
+            ledger.check(1, Part::One, &Answer::Int(120)),
+            Err(Refusal::TooHigh {
+                bound: Answer::Int(100)
+            })
+        );
+        assert_eq!(
+            ledger.check(1, Part::One, &Answer::Int(40)),
+            Err(Refusal::KnownWrong {
+                outcome: Submission::TooLow
+            })
+        );
+        assert_eq!(ledger.check(1, Part::Two, &Answer::Int(120)), Ok(()));
+        // Text has no bounds, and big integers do
+        assert_eq!(ledger.check(1, Part::One, &"x".into()), Ok(()));
+        assert!(ledger.check(1, Part::One, &u128::MAX.into()).is_err());
+
+        ledger.record(1, Part::One, &Answer::Int(70), Submission::Correct);
+        assert_eq!(
+            ledger.check(1, Part::One, &Answer::Int(71)),
+            Err(Refusal::Solved {
+                answer: Answer::Int(70)
+            })
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut ledger = Ledger::default();
+        ledger.record(3, Part::Two, &"a b".into(), Submission::TooLow);
+        ledger.record(3, Part::Two, &"123".into(), Submission::Wrong);
+        ledger.record(3, Part::Two, &"x\n\"y\"".into(), Submission::Wrong);
+        ledger.record(3, Part::Two, &Answer::Int(7), Submission::Correct);
+        let text = ledger.to_string();
+        assert_eq!(
+            text,
+            "3 2 too_low \"a b\"\n3 2 wrong \"123\"\n\
+             3 2 wrong \"x\\n\\\"y\\\"\"\n3 2 correct 7\n"
+        );
+        let path = Path::new(LEDGER_FILE);
+        let parsed = Ledger::parse(&text, path).unwrap();
+        assert_eq!(parsed.entries, ledger.entries);
+
+        for line in ["3 2 maybe 7", "3 2 wrong a b", "3 2 wrong \"a"] {
+            let err = Ledger::parse(line, path).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "submissions.txt:1: expected `day part outcome answer`"
+            );
+        }
+    }
+}
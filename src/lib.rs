@@ -1,26 +1,153 @@
-use std::fs;
-
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-
-pub fn read_as_string(day: u8, filename: &str) -> String {
-    let filename = format!("inputs/{day:02}-{filename}.txt");
-    fs::read_to_string(filename).unwrap()
-}
-
-pub fn read_input(day: u8) -> String {
-    read_as_string(day, "input")
-}
-pub fn read_example(day: u8) -> String {
-    read_as_string(day, "example")
+mod answer;
+mod answers;
+mod bench;
+mod bignum;
+mod cache;
+mod cancel;
+mod client;
+mod dot;
+mod error;
+mod graph;
+mod grid;
+mod input;
+mod interval;
+mod ledger;
+mod parallel;
+mod report;
+mod rng;
+mod scaffold;
+mod solver;
+mod union_find;
+mod watch;
+
+pub use answer::Answer;
+pub use answers::{Answers, Verdict, ANSWERS_FILE};
+pub use bench::{baseline_file, Baseline, BenchConfig, DayBench, Stats};
+pub use bignum::{BigUint, ParseBigUintError};
+pub use cache::{
+    checksum, format_time, inspect, normalise, Fetched, InputStatus,
+    Manifest, Problem, MANIFEST_FILE,
+};
+pub use cancel::{check_cancelled, run_with_timeout, Cancel};
+pub use client::{
+    session, Client, Submission, BASE_URL_VAR, DEFAULT_BASE_URL, SESSION_VAR,
+};
+pub use dot::{Attrs, Dot};
+pub use error::{Error, ParseError, Result};
+pub use graph::{
+    Cycle, Graph, NodeId, Paths, Route, RouteError, RoutePaths, MAX_UNORDERED,
+};
+pub use grid::{Grid, Pos};
+pub use input::{
+    Example, InputSource, Inputs, DEFAULT_INPUT_DIR, INPUT_DIR_VAR,
+};
+pub use interval::{Integer, IntervalSet};
+pub use ledger::{Entry, Ledger, Refusal, LEDGER_FILE};
+pub use parallel::ordered_map;
+pub use report::{DayReport, Format, PartReport, ReportWriter, Status};
+pub use rng::Rng;
+pub use scaffold::new_day;
+pub use solver::{Part, Puzzle, Solver};
+pub use union_find::{RollbackUnionFind, UnionFind};
+pub use watch::{Outcome, Watcher};
+
+pub mod y2025;
+
+static PUZZLES: &[Puzzle] = &[
+    // register puzzle here
+    Puzzle::of::<y2025::day01::Day01>(),
+    Puzzle::of::<y2025::day02::Day02>(),
+    Puzzle::of::<y2025::day03::Day03>(),
+    Puzzle::of::<y2025::day04::Day04>(),
+    Puzzle::of::<y2025::day05::Day05>(),
+    Puzzle::of::<y2025::day06::Day06>(),
+    Puzzle::of::<y2025::day07::Day07>(),
+    Puzzle::of::<y2025::day08::Day08>(),
+    Puzzle::of::<y2025::day09::Day09>(),
+    Puzzle::of::<y2025::day10::Day10>(),
+    Puzzle::of::<y2025::day11::Day11>(),
+    Puzzle::of::<y2025::day12::Day12>(),
+];
+
+/// All registered puzzles, ordered by year and day.
+pub fn puzzles() -> &'static [Puzzle] {
+    PUZZLES
+}
+
+/// The puzzles of one event, ordered by day.
+pub fn puzzles_of(year: u16) -> impl Iterator<Item = &'static Puzzle> {
+    PUZZLES.iter().filter(move |p| p.year() == year)
+}
+
+pub fn puzzle(year: u16, day: u8) -> Option<&'static Puzzle> {
+    puzzles_of(year).find(|p| p.day() == day)
+}
+
+/// The most recent year with a registered puzzle.
+pub fn latest_year() -> u16 {
+    PUZZLES.last().map_or(y2025::YEAR, Puzzle::year)
+}
+
+pub fn read_as_string(year: u16, day: u8, filename: &str) -> Result<String> {
+    Inputs::for_year(year).load(day, filename)
+}
+
+pub fn read_input(year: u16, day: u8) -> Result<String> {
+    read_as_string(year, day, "input")
+}
+pub fn read_example(year: u16, day: u8) -> Result<String> {
+    read_as_string(year, day, "example")
+}
+/// The first example input that applies to `part`, see [`Example`].
+pub fn read_example_for(year: u16, day: u8, part: Part) -> Result<String> {
+    let inputs = Inputs::for_year(year);
+    match inputs.examples_for(day, part)?.into_iter().next() {
+        Some(example) => Ok(example.input),
+        None => Err(Error::Io {
+            path: inputs.path(day, "example"),
+            source: std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("no example for {part}"),
+            ),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_is_ordered_by_day() {
+        // `aoc new` appends days, so only the solved ones are fixed here
+        let days: Vec<u8> = puzzles_of(2025).map(|p| p.day()).collect();
+        assert_eq!(days[..12], (1..=12).collect::<Vec<u8>>());
+        let keys: Vec<(u16, u8)> =
+            puzzles().iter().map(|p| (p.year(), p.day())).collect();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(puzzle(2025, 11).unwrap().title(), "Reactor");
+        assert!(puzzle(2025, 0).is_none());
+        assert!(puzzle(2015, 11).is_none());
+    }
+
+    #[test]
+    fn examples_match_expected() -> Result<()> {
+        for puzzle in puzzles() {
+            let inputs = Inputs::for_year(puzzle.year());
+            for example in inputs.examples(puzzle.day())? {
+                for (&part, expected) in &example.expected {
+                    let answer = puzzle.solve(part, &example.input)?;
+                    assert_eq!(
+                        &answer,
+                        expected,
+                        "{} day {} {part} on {}",
+                        puzzle.year(),
+                        puzzle.day(),
+                        example.name
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
 }
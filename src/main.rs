@@ -1,74 +1,862 @@
 use std::env;
-use std::fmt::Display;
-use std::time::SystemTime;
-
-fn main() {
-    macro_rules! puzzle {
-        ($mod:ident, $title:expr) => {
-            (
-                $title,
-                |input| Box::new(aoc::$mod::part_one(input)),
-                |input| Box::new(aoc::$mod::part_two(input)),
-            )
+use std::io;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::y2025::day11;
+use aoc::{
+    Answer, Answers, Baseline, BenchConfig, Client, DayReport, Format,
+    InputSource, InputStatus, Inputs, Ledger, Outcome, Part, PartReport,
+    Puzzle, ReportWriter, Rng, Status, Submission, Watcher,
+};
+
+// Options that take a value, e.g. `--runs 50`
+const VALUE_OPTIONS: &[&str] = &[
+    "--runs",
+    "--warmup",
+    "--baseline",
+    "--format",
+    "--input-dir",
+    "--input",
+    "--jobs",
+    "--timeout",
+    "--title",
+    "--interval",
+    "--year",
+    "--from",
+    "--to",
+    "--via",
+    "--avoid",
+    "--list",
+    "--shortest",
+    "--sample",
+    "--seed",
+];
+
+#[derive(Default)]
+struct Args {
+    command: Option<String>,
+    /// Arguments after the command, usually days.
+    positional: Vec<String>,
+    flags: Vec<String>,
+    values: Vec<(String, String)>,
+}
+
+impl Args {
+    fn parse() -> Result<Self, String> {
+        let mut args = Args::default();
+        let mut iter = env::args().skip(1);
+        while let Some(arg) = iter.next() {
+            if VALUE_OPTIONS.contains(&arg.as_str()) {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("{arg} expects a value"))?;
+                args.values.push((arg, value));
+            } else if arg.starts_with("--") {
+                args.flags.push(arg);
+            } else if args.command.is_none()
+                && args.positional.is_empty()
+                && arg.parse::<u8>().is_err()
+            {
+                args.command = Some(arg);
+            } else {
+                args.positional.push(arg);
+            }
+        }
+        Ok(args)
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|f| f == name)
+    }
+
+    fn value(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .rev()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn all_values<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        self.values
+            .iter()
+            .filter(move |(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn number(&self, name: &str, default: usize) -> Result<usize, String> {
+        match self.value(name) {
+            None => Ok(default),
+            Some(value) => value.parse().map_err(|_| {
+                format!("{name} expects a number, got `{value}`")
+            }),
+        }
+    }
+
+    fn seconds(&self, name: &str) -> Result<Option<Duration>, String> {
+        let Some(value) = self.value(name) else {
+            return Ok(None);
         };
+        match value.parse::<f64>() {
+            Ok(secs) if secs > 0.0 && secs.is_finite() => {
+                Ok(Some(Duration::from_secs_f64(secs)))
+            }
+            _ => Err(format!("{name} expects seconds, got `{value}`")),
+        }
     }
 
-    type SolverFn = fn(&str) -> Box<dyn Display>;
-
-    let puzzles: Vec<(&str, SolverFn, SolverFn)> = vec![
-        // register puzzle here
-        puzzle!(day01, "Secret Entrance"),
-        puzzle!(day02, "Gift Shop"),
-        puzzle!(day03, "Lobby"),
-        puzzle!(day04, "Printing Department"),
-        puzzle!(day05, "Cafeteria"),
-        puzzle!(day06, "Trash Compactor"),
-        puzzle!(day07, "Laboratories"),
-        puzzle!(day08, "Playground"),
-        puzzle!(day09, "Movie Theater"),
-        puzzle!(day10, "Factory"),
-        puzzle!(day11, "Reactor"),
-        puzzle!(day12, "Christmas Tree Farm"),
-    ];
-
-    let filename = match env::args().find(|a| a == "--example") {
-        None => "input",
-        Some(_) => "example",
+    // `--year YYYY`, defaulting to the latest year with a puzzle
+    fn year(&self) -> Result<u16, String> {
+        match self.value("--year") {
+            None => Ok(aoc::latest_year()),
+            Some(value) => value
+                .parse()
+                .map_err(|_| format!("--year expects a year, got `{value}`")),
+        }
+    }
+
+    fn input_name(&self) -> &'static str {
+        if self.flag("--example") {
+            "example"
+        } else {
+            "input"
+        }
+    }
+
+    // `--input-dir DIR` and `--input [DAY=]PATH`, where PATH may be `-`
+    fn inputs(&self) -> Result<Inputs, String> {
+        let mut inputs = Inputs::default();
+        inputs.set_year(self.year()?);
+        if let Some(dir) = self.value("--input-dir") {
+            inputs.set_dir(dir);
+        }
+
+        let mut stdin_days = 0;
+        for value in self.all_values("--input") {
+            let (day, path) = match value.split_once('=') {
+                Some((day, path)) => {
+                    let day = day
+                        .parse()
+                        .map_err(|_| format!("invalid day in `{value}`"))?;
+                    (day, path)
+                }
+                None => match self.days()?[..] {
+                    [day] => (day, value),
+                    _ => {
+                        let message =
+                            "--input without DAY= needs a single day";
+                        return Err(message.to_string());
+                    }
+                },
+            };
+            let source = InputSource::from_arg(path);
+            if source == InputSource::Stdin {
+                stdin_days += 1;
+            }
+            inputs.set_override(day, source);
+        }
+        if stdin_days > 1 {
+            return Err("only one day can read from stdin".to_string());
+        }
+        Ok(inputs)
+    }
+
+    fn days(&self) -> Result<Vec<u8>, String> {
+        if self.positional.is_empty() {
+            let year = self.year()?;
+            return Ok(aoc::puzzles_of(year).map(|p| p.day()).collect());
+        }
+        self.positional
+            .iter()
+            .map(|arg| {
+                arg.parse()
+                    .map_err(|_| format!("unexpected argument `{arg}`"))
+            })
+            .collect()
+    }
+}
+
+fn main() -> ExitCode {
+    let result =
+        Args::parse().and_then(|args| match args.command.as_deref() {
+            None => run(&args),
+            Some("bench") => bench(&args),
+            Some("fetch") => fetch(&args),
+            Some("status") => status(&args),
+            Some("new") => new(&args),
+            Some("paths") => paths(&args),
+            Some("submit") => submit(&args),
+            Some("watch") => watch(&args),
+            Some(command) => Err(format!("unknown command `{command}`")),
+        });
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Solves the selected days, returning whether every part succeeded
+fn run(args: &Args) -> Result<bool, String> {
+    if args.flag("--dot") {
+        return dot(args);
+    }
+    let filename = args.input_name();
+    let inputs = args.inputs()?;
+    let record = args.flag("--record");
+    let format: Format = args.value("--format").unwrap_or("text").parse()?;
+
+    // Recorded answers only describe the real inputs
+    let verify = filename == "input";
+    if args.flag("--submit") && !verify {
+        return Err("--submit only works with the real inputs".to_string());
+    }
+    let answers_path = inputs.dir().join(aoc::ANSWERS_FILE);
+    let mut answers = if verify {
+        Answers::load(&answers_path).map_err(|err| err.to_string())?
+    } else {
+        Answers::default()
     };
 
-    let show_time = env::args().any(|a| a == "--time");
+    let jobs = match args.number("--jobs", 1)? {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        jobs => jobs,
+    };
+    let timeout = args.seconds("--timeout")?;
 
-    let mut days: Vec<usize> =
-        env::args().filter_map(|a| a.parse().ok()).collect();
+    // Inputs are read up front and in day order, so only solving runs in
+    // parallel and a day reading stdin does so before anything else
+    let mut failed = false;
+    let mut reports = Vec::new();
+    let mut tasks = Vec::new();
+    for day in args.days()? {
+        let Some(puzzle) = aoc::puzzle(inputs.year(), day) else {
+            eprintln!("Day {day} of {} is not registered", inputs.year());
+            failed = true;
+            continue;
+        };
 
-    if days.is_empty() {
-        days = (1..=puzzles.len()).collect();
+        let mut report = DayReport {
+            day,
+            title: puzzle.title(),
+            error: None,
+            parts: Vec::new(),
+        };
+        match cases(&inputs, puzzle, filename) {
+            Err(err) => report.error = Some(err.to_string()),
+            Ok(cases) => {
+                let index = reports.len();
+                tasks.extend(cases.into_iter().map(|case| (index, case)));
+            }
+        }
+        reports.push(report);
     }
 
-    for day in days {
-        let (title, part1, part2) = &puzzles[day - 1];
-        let input = aoc::read_as_string(day as u8, filename);
-        let input = input.as_str();
+    // Parts still running per day; a day is written once it has none left
+    // and every day before it has been written
+    let mut remaining = vec![0; reports.len()];
+    for &(index, _) in &tasks {
+        remaining[index] += 1;
+    }
+    let mut written = 0;
 
-        println!("--- Day {day}: {title} ---");
-        let t0 = SystemTime::now();
-        println!("Part One: {}", part1(input));
-        let t1 = SystemTime::now();
-        if filename == "example" && day == 14 {
-            // example of day 14 part two has different input
-            let input = aoc::read_as_string(day as u8, "example-2");
-            let input = input.as_str();
-            println!("Part Two: {}", part2(input));
-        } else {
-            println!("Part Two: {}", part2(input));
+    let stdout = io::stdout().lock();
+    let mut writer = ReportWriter::new(stdout, format, args.flag("--time"))
+        .map_err(|err| err.to_string())?;
+    let mut write_error = None;
+    let mut write_finished = |reports: &[DayReport], remaining: &[usize]| {
+        while written < reports.len() && remaining[written] == 0 {
+            let report = &reports[written];
+            failed |= report.failed();
+            if let Err(err) = writer.write(report) {
+                write_error.get_or_insert(err);
+            }
+            written += 1;
+        }
+    };
+    write_finished(&reports, &remaining);
+
+    let solve = |(index, mut case): (usize, Case)| {
+        let (puzzle, part) = (case.puzzle, case.part);
+        let start = Instant::now();
+        let answer = match timeout {
+            None => puzzle.solve(part, &case.input),
+            Some(timeout) => {
+                let input = mem::take(&mut case.input);
+                aoc::run_with_timeout(timeout, move || {
+                    puzzle.solve(part, &input)
+                })
+            }
+        };
+        (index, case, answer, start.elapsed())
+    };
+    aoc::ordered_map(jobs, tasks, solve, |_, (index, case, answer, time)| {
+        let report = &mut reports[index];
+        // Explicit input files may not be the ones the answers describe
+        let verify = verify && !inputs.is_overridden(report.day);
+        let status = match (&answer, case.expected) {
+            (Err(aoc::Error::Cancelled), _) => Status::Timeout,
+            (Err(_), _) => Status::Error,
+            (Ok(Answer::Unsolved), _) => Status::Unsolved,
+            (Ok(answer), Some(expected)) => {
+                if *answer == expected {
+                    Status::Pass
+                } else {
+                    Status::Fail { expected }
+                }
+            }
+            (Ok(_), None) if !verify => Status::Unchecked,
+            (Ok(answer), None) if record => {
+                answers.set(report.day, case.part, answer.clone());
+                Status::Recorded
+            }
+            (Ok(answer), None) => {
+                answers.verify(report.day, case.part, answer).into()
+            }
+        };
+        report.parts.push(PartReport {
+            part: case.part,
+            input: case.name,
+            answer: answer.map_err(|err| match err {
+                aoc::Error::Cancelled => {
+                    format!("cancelled after {time:.2?}")
+                }
+                err => err.to_string(),
+            }),
+            time,
+            status,
+        });
+
+        remaining[index] -= 1;
+        write_finished(&reports, &remaining);
+    });
+    if let Some(err) = write_error {
+        return Err(err.to_string());
+    }
+    drop(writer.finish().map_err(|err| err.to_string())?);
+
+    if record && verify {
+        answers.save(&answers_path).map_err(|err| err.to_string())?;
+    }
+
+    if args.flag("--submit") {
+        let mut submitter = Submitter::new(&inputs)?;
+        'days: for report in &reports {
+            if inputs.is_overridden(report.day) {
+                continue;
+            }
+            for part in &report.parts {
+                // Answers that disagree with answers.toml are not sent
+                let Ok(answer) = &part.answer else { continue };
+                if part.status.is_failure() || !answer.is_solved() {
+                    continue;
+                }
+                match submitter.submit(report.day, part.part, answer)? {
+                    Some(Submission::RateLimited { .. }) => break 'days,
+                    Some(outcome) if outcome.is_wrong() => failed = true,
+                    _ => {}
+                }
+            }
         }
-        let t2 = SystemTime::now();
+    }
+
+    Ok(!failed)
+}
+
+// One part solved against one input
+struct Case {
+    puzzle: &'static Puzzle,
+    part: Part,
+    name: String,
+    input: String,
+    /// Known answer for an example, checked in place of the answers file
+    expected: Option<Answer>,
+}
+
+// Pairs each part with its input. Examples are discovered per part, so a
+// day whose parts use different examples runs each against its own.
+fn cases(
+    inputs: &Inputs,
+    puzzle: &'static Puzzle,
+    filename: &str,
+) -> aoc::Result<Vec<Case>> {
+    let day = puzzle.day();
+    if filename != "example" || inputs.is_overridden(day) {
+        let input = inputs.load(day, filename)?;
+        let cases = Part::ALL.map(|part| Case {
+            puzzle,
+            part,
+            name: filename.to_string(),
+            input: input.clone(),
+            expected: None,
+        });
+        return Ok(cases.into());
+    }
+
+    let examples = inputs.examples(day)?;
+    let mut cases = Vec::new();
+    for part in Part::ALL {
+        for example in examples.iter().filter(|e| e.applies_to(part)) {
+            cases.push(Case {
+                puzzle,
+                part,
+                name: example.name.clone(),
+                input: example.input.clone(),
+                expected: example.expected.get(&part).cloned(),
+            });
+        }
+    }
+    Ok(cases)
+}
+
+// Prints the input of each selected day as a Graphviz DOT graph instead of
+// solving it. Without explicit days, those that cannot draw are skipped.
+fn dot(args: &Args) -> Result<bool, String> {
+    let inputs = args.inputs()?;
+    let year = inputs.year();
+    let mut failed = false;
+    for day in args.days()? {
+        let Some(puzzle) = aoc::puzzle(year, day) else {
+            eprintln!("Day {day} of {year} is not registered");
+            failed = true;
+            continue;
+        };
+        let drawn = inputs
+            .load(day, args.input_name())
+            .and_then(|input| puzzle.dot(&input));
+        match drawn {
+            Ok(Some(dot)) => print!("{dot}"),
+            Ok(None) if args.positional.is_empty() => {}
+            Ok(None) => {
+                eprintln!("Day {day}: no DOT output");
+                failed = true;
+            }
+            Err(err) => {
+                eprintln!("Day {day}: {err}");
+                failed = true;
+            }
+        }
+    }
+    Ok(!failed)
+}
+
+// Benchmarks the selected days, comparing against a saved baseline
+fn bench(args: &Args) -> Result<bool, String> {
+    let defaults = BenchConfig::default();
+    let config = BenchConfig {
+        warmup: args.number("--warmup", defaults.warmup)?,
+        runs: args.number("--runs", defaults.runs)?.max(1),
+    };
+    let inputs = args.inputs()?;
+    let year = inputs.year();
+    let path = args
+        .value("--baseline")
+        .map_or_else(|| aoc::baseline_file(year), PathBuf::from);
+    let baseline = Baseline::load(&path).map_err(|err| err.to_string())?;
+    let mut current = baseline.clone();
+
+    println!("{} warmup, {} timed runs", config.warmup, config.runs);
+    println!();
+
+    let mut failed = false;
+    for day in args.days()? {
+        let Some(puzzle) = aoc::puzzle(inputs.year(), day) else {
+            eprintln!("Day {day} of {} is not registered", inputs.year());
+            failed = true;
+            continue;
+        };
+
+        println!("--- Day {day}: {} ---", puzzle.title());
+        let result = inputs
+            .load(day, args.input_name())
+            .and_then(|input| puzzle.bench(&input, &config));
+        let timings = match result {
+            Ok(timings) => timings,
+            Err(err) => {
+                println!("Error: {err}");
+                println!();
+                failed = true;
+                continue;
+            }
+        };
 
-        if show_time {
-            let d1 = t1.duration_since(t0).unwrap_or_default();
-            let d2 = t2.duration_since(t1).unwrap_or_default();
-            println!("Duration: {:?}", (d1, d2));
+        for (stage, stats) in timings.stages() {
+            print!("{stage:<8}  {stats}");
+            match baseline.delta(day, stage, stats.median) {
+                Some(delta) => println!("  ({delta:+.1}%)"),
+                None => println!(),
+            }
         }
+        current.record(day, &timings);
         println!();
     }
+
+    if args.flag("--save-baseline") {
+        current.save(&path).map_err(|err| err.to_string())?;
+        println!("Baseline saved to {}", path.display());
+    }
+
+    Ok(!failed)
+}
+
+const FETCH_HELP: &str = "\
+usage: aoc fetch <day>... [--force] [--year YYYY] [--input-dir DIR]
+
+Downloads the real inputs of the given days, keeping existing files unless
+--force is given. The session cookie comes from $AOC_SESSION, .aoc-session
+or ~/.aoc-session.
+
+The real site is https only, and https requests are made by running `curl`,
+which must be on PATH; the crate uses std alone, which has no TLS. Plain
+http:// servers set in $AOC_BASE_URL, such as a local mock, need no curl.
+";
+
+// Downloads the real inputs of the given days, keeping existing files
+// unless `--force` is given
+fn fetch(args: &Args) -> Result<bool, String> {
+    if args.flag("--help") {
+        print!("{FETCH_HELP}");
+        return Ok(true);
+    }
+    if args.positional.is_empty() {
+        return Err("usage: aoc fetch <day>...".to_string());
+    }
+    let inputs = args.inputs()?;
+    let client =
+        Client::from_env(inputs.year()).map_err(|err| err.to_string())?;
+
+    let mut failed = false;
+    for day in args.days()? {
+        let path = inputs.path(day, "input");
+        if path.exists() && !args.flag("--force") {
+            println!("Day {day}: {} already exists", path.display());
+            continue;
+        }
+        let saved = client
+            .fetch_input(day)
+            .and_then(|input| inputs.store(day, &input));
+        match saved {
+            Ok(path) => println!("Day {day}: saved to {}", path.display()),
+            Err(err) => {
+                eprintln!("Day {day}: {err}");
+                failed = true;
+            }
+        }
+    }
+    Ok(!failed)
+}
+
+// Generates and registers a module for a new day, run from the crate root
+fn new(args: &Args) -> Result<bool, String> {
+    let [day] = args.days()?[..] else {
+        return Err("usage: aoc new <day> [--title TITLE]".to_string());
+    };
+    let title = match args.value("--title") {
+        Some(title) => title.to_string(),
+        None => format!("Day {day}"),
+    };
+    let inputs = args.inputs()?;
+    let written = aoc::new_day(Path::new("."), &inputs, day, &title)
+        .map_err(|err| err.to_string())?;
+    for path in written {
+        println!("Wrote {}", path.display());
+    }
+    Ok(true)
+}
+
+// Counts paths through the reactor of 2025 day 11 between named devices,
+// by default those part one counts, or lists some of them
+fn paths(args: &Args) -> Result<bool, String> {
+    let listings = ["--list", "--shortest", "--sample"];
+    let listing: Vec<&str> = (listings.iter())
+        .filter(|option| args.value(option).is_some())
+        .copied()
+        .collect();
+    if !args.positional.is_empty() || listing.len() > 1 {
+        let usage = "usage: aoc paths [--from NODE] [--to NODE] \
+                     [--via NODE]... [--ordered] [--avoid NODE]... \
+                     [--list N | --shortest N | --sample N [--seed SEED] \
+                     | --dot]";
+        return Err(usage.to_string());
+    }
+    let mut inputs = args.inputs()?;
+    inputs.set_year(aoc::y2025::YEAR);
+    let graph = inputs
+        .load(11, args.input_name())
+        .and_then(|input| Ok(aoc::Graph::parse(11, &input)?))
+        .map_err(|err| err.to_string())?;
+
+    let names = |option| args.all_values(option).map(str::to_string);
+    let query = day11::Query {
+        via: names("--via").collect(),
+        ordered: args.flag("--ordered"),
+        avoid: names("--avoid").collect(),
+        ..day11::Query::new(
+            args.value("--from").unwrap_or("you"),
+            args.value("--to").unwrap_or("out"),
+        )
+    };
+    if args.flag("--dot") {
+        let dot =
+            day11::dot(&graph, &query).map_err(|err| err.to_string())?;
+        print!("{dot}");
+        return Ok(true);
+    }
+    let paths =
+        day11::paths(&graph, &query).map_err(|err| err.to_string())?;
+    let Some(&option) = listing.first() else {
+        println!("{}", paths.count());
+        return Ok(true);
+    };
+
+    let n = args.number(option, 0)?;
+    let listed = match option {
+        "--list" => paths.iter().take(n).collect(),
+        "--shortest" => paths.shortest(n),
+        _ => {
+            let mut rng = match args.value("--seed") {
+                None => Rng::from_time(),
+                Some(_) => Rng::new(args.number("--seed", 0)? as u64),
+            };
+            (0..n).map_while(|_| paths.sample(&mut rng)).collect()
+        }
+    };
+    for path in listed {
+        println!("{}", graph.format_path(&path));
+    }
+    Ok(true)
+}
+
+// Rebuilds and re-runs one day whenever its module or input files change,
+// printing a one-line summary each time; run from the crate root
+fn watch(args: &Args) -> Result<bool, String> {
+    let [day] = args.days()?[..] else {
+        return Err("usage: aoc watch <day> [--interval SECS]".to_string());
+    };
+    let interval = args
+        .seconds("--interval")?
+        .unwrap_or(Duration::from_millis(500));
+    let inputs = args.inputs()?;
+    let year = inputs.year();
+    let source = Path::new("src")
+        .join(format!("y{year}"))
+        .join(format!("day{day:02}.rs"));
+    let mut watcher = Watcher::new(&source, &inputs.dir(), day);
+    if watcher.files().next().is_none() {
+        return Err(format!("nothing to watch for day {day} of {year}"));
+    }
+
+    loop {
+        println!("{}", rerun(args, year, day)?);
+        watcher.wait(interval);
+    }
+}
+
+// Runs the examples and then the real input of `day` through a fresh build,
+// since the watching process still has the old code
+fn rerun(args: &Args, year: u16, day: u8) -> Result<String, String> {
+    let started = Instant::now();
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let profile = if cfg!(debug_assertions) {
+        &[][..]
+    } else {
+        &["--release"][..]
+    };
+    let build = Command::new(&cargo)
+        .args(["build", "--quiet", "--bin", "aoc"])
+        .args(profile)
+        .output()
+        .map_err(|err| format!("cannot run {cargo}: {err}"))?;
+    if !build.status.success() {
+        let errors = String::from_utf8_lossy(&build.stderr);
+        return Ok(format!("Day {day}: BUILD FAILED\n{}", errors.trim_end()));
+    }
+
+    let mut groups = Vec::new();
+    let mut details = Vec::new();
+    for input in [Some("--example"), None] {
+        let mut command = Command::new(&cargo);
+        command
+            .args(["run", "--quiet", "--bin", "aoc"])
+            .args(profile)
+            .args(["--", &day.to_string(), "--format", "csv"])
+            .args(["--year", &year.to_string()])
+            .args(input);
+        for option in ["--input-dir", "--timeout"] {
+            if let Some(value) = args.value(option) {
+                command.args([option, value]);
+            }
+        }
+        let output = command
+            .output()
+            .map_err(|err| format!("cannot run {cargo}: {err}"))?;
+
+        let outcomes =
+            Outcome::parse_csv(&String::from_utf8_lossy(&output.stdout));
+        if outcomes.is_empty() {
+            // The run stopped before solving, e.g. on a missing input
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let error = stderr.lines().last().unwrap_or("no output");
+            let name = input.map_or("input", |_| "example");
+            groups.push(format!("{name} {error}"));
+            continue;
+        }
+        for outcome in outcomes.iter().filter(|o| !o.error.is_empty()) {
+            details.push(format!(
+                "  {} p{}: {}",
+                outcome.input, outcome.part, outcome.error
+            ));
+        }
+        let shown: Vec<String> =
+            outcomes.iter().map(Outcome::to_string).collect();
+        groups.push(shown.join(", "));
+    }
+
+    let mut summary = format!(
+        "Day {day}: {} ({:.1?})",
+        groups.join(" | "),
+        started.elapsed()
+    );
+    for line in details {
+        summary.push('\n');
+        summary.push_str(&line);
+    }
+    Ok(summary)
+}
+
+// Lists which real inputs are missing or fail their integrity checks
+fn status(args: &Args) -> Result<bool, String> {
+    let inputs = args.inputs()?;
+    let mut healthy = true;
+    for day in args.days()? {
+        let status = inputs.status(day).map_err(|err| err.to_string())?;
+        let summary = match status {
+            InputStatus::Fetched(fetched) => format!(
+                "ok, fetched {}, checksum {:016x}",
+                aoc::format_time(fetched.time),
+                fetched.checksum
+            ),
+            InputStatus::Unrecorded => "ok, not fetched by aoc".to_string(),
+            InputStatus::Missing => {
+                healthy = false;
+                "missing".to_string()
+            }
+            InputStatus::Suspicious(problem) => {
+                healthy = false;
+                format!("SUSPICIOUS, {problem}")
+            }
+        };
+        println!("Day {day:>2}: {summary}");
+    }
+    Ok(healthy)
+}
+
+// Submits an answer, solving the real input when none is given
+fn submit(args: &Args) -> Result<bool, String> {
+    let usage = || "usage: aoc submit <day> <part> [answer]".to_string();
+    let (day, part, answer) = match &args.positional[..] {
+        [day, part] => (day, part, None),
+        [day, part, answer] => (day, part, Some(answer.clone())),
+        _ => return Err(usage()),
+    };
+    let day: u8 = day.parse().map_err(|_| usage())?;
+    let part = part
+        .parse()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or_else(usage)?;
+
+    let answer = match answer {
+        Some(answer) => Answer::parse(&answer),
+        None => {
+            let inputs = args.inputs()?;
+            let year = inputs.year();
+            let puzzle = aoc::puzzle(year, day).ok_or_else(|| {
+                format!("Day {day} of {year} is not registered")
+            })?;
+            inputs
+                .load(day, "input")
+                .and_then(|input| puzzle.solve(part, &input))
+                .map_err(|err| err.to_string())?
+        }
+    };
+    if !answer.is_solved() {
+        return Err(format!("Day {day} {part} is unsolved, nothing to send"));
+    }
+
+    let mut submitter = Submitter::new(&args.inputs()?)?;
+    let outcome = submitter.submit(day, part, &answer)?;
+    Ok(matches!(
+        outcome,
+        Some(Submission::Correct | Submission::AlreadySolved)
+    ))
+}
+
+// Sends answers by way of the ledger, which refuses any that earlier
+// responses already rule out. Correct answers are added to answers.toml.
+struct Submitter {
+    client: Client,
+    ledger: Ledger,
+    ledger_path: PathBuf,
+    answers_path: PathBuf,
+}
+
+impl Submitter {
+    fn new(inputs: &Inputs) -> Result<Self, String> {
+        let ledger_path = inputs.dir().join(aoc::LEDGER_FILE);
+        let client =
+            Client::from_env(inputs.year()).map_err(|err| err.to_string())?;
+        Ok(Self {
+            client,
+            ledger: Ledger::load(&ledger_path)
+                .map_err(|err| err.to_string())?,
+            ledger_path,
+            answers_path: inputs.dir().join(aoc::ANSWERS_FILE),
+        })
+    }
+
+    // Returns the server's verdict, or None if the ledger refused
+    fn submit(
+        &mut self,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<Option<Submission>, String> {
+        if let Err(refusal) = self.ledger.check(day, part, answer) {
+            eprintln!("Day {day} {part}: not submitting {answer}, {refusal}");
+            return Ok(None);
+        }
+
+        eprintln!("Day {day} {part}: submitting {answer}");
+        let outcome = self
+            .client
+            .submit(day, part, &answer.to_string())
+            .map_err(|err| err.to_string())?;
+        eprintln!("Day {day} {part}: {outcome}");
+
+        self.ledger.record(day, part, answer, outcome);
+        self.ledger
+            .save(&self.ledger_path)
+            .map_err(|err| err.to_string())?;
+        if outcome == Submission::Correct {
+            let mut answers = Answers::load(&self.answers_path)
+                .map_err(|err| err.to_string())?;
+            answers.set(day, part, answer.clone());
+            answers
+                .save(&self.answers_path)
+                .map_err(|err| err.to_string())?;
+        }
+        Ok(Some(outcome))
+    }
 }
@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+
+/// Applies `f` to `items` on up to `jobs` threads.
+///
+/// Results are handed to `sink` on the calling thread in the order of
+/// `items`, each as soon as it and everything before it have finished, so
+/// output can be streamed while later items are still running. With one
+/// job everything runs on the calling thread.
+pub fn ordered_map<T, R, F, S>(jobs: usize, items: Vec<T>, f: F, mut sink: S)
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+    S: FnMut(usize, R),
+{
+    let workers = jobs.min(items.len());
+    if workers <= 1 {
+        for (index, item) in items.into_iter().enumerate() {
+            sink(index, f(item));
+        }
+        return;
+    }
+
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let (queue, f, sender) = (&queue, &f, sender.clone());
+            scope.spawn(move || loop {
+                // Take the lock only long enough to claim the next item
+                let next = queue.lock().unwrap().next();
+                let Some((index, item)) = next else {
+                    break;
+                };
+                if sender.send((index, f(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&next) {
+                sink(next, result);
+                next += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn results_keep_item_order() {
+        for jobs in [1, 3, 8] {
+            let items: Vec<u64> = (0..20).collect();
+            let mut seen = Vec::new();
+            ordered_map(
+                jobs,
+                items,
+                |n| {
+                    // Finish early items last to force reordering
+                    thread::sleep(Duration::from_millis(20 - n));
+                    n * n
+                },
+                |index, square| seen.push((index, square)),
+            );
+            let expected: Vec<(usize, u64)> =
+                (0..20).map(|n| (n as usize, n * n)).collect();
+            assert_eq!(seen, expected, "{jobs} jobs");
+        }
+    }
+}
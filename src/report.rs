@@ -0,0 +1,445 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::{Answer, Part, Verdict};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format `{s}`, expected json|csv|text")),
+        }
+    }
+}
+
+/// Outcome of checking a part, as written in the `status` field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: Answer,
+    },
+    Unknown,
+    Recorded,
+    /// The solver gave [`Answer::Unsolved`], so there is nothing to check.
+    Unsolved,
+    /// Not compared against anything, e.g. an example input.
+    Unchecked,
+    Error,
+    /// Solving was cancelled after running past `--timeout`.
+    Timeout,
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "fail",
+            Status::Unknown => "unknown",
+            Status::Recorded => "recorded",
+            Status::Unsolved => "unsolved",
+            Status::Unchecked => "unchecked",
+            Status::Error => "error",
+            Status::Timeout => "timeout",
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail { .. } | Status::Error | Status::Timeout)
+    }
+}
+
+impl From<Verdict> for Status {
+    fn from(verdict: Verdict) -> Self {
+        match verdict {
+            Verdict::Pass => Status::Pass,
+            Verdict::Fail { expected } => Status::Fail { expected },
+            Verdict::Unknown => Status::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Fail { expected } => {
+                write!(f, "FAIL, expected {expected}")
+            }
+            status => f.write_str(&status.name().to_uppercase()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: Part,
+    /// Name of the input solved, e.g. `input` or `example2`.
+    pub input: String,
+    /// The answer, or the error message when solving failed.
+    pub answer: Result<Answer, String>,
+    pub time: Duration,
+    pub status: Status,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    pub title: &'static str,
+    /// Set when the input could not be loaded and nothing was solved.
+    pub error: Option<String>,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    pub fn failed(&self) -> bool {
+        self.error.is_some()
+            || self.parts.iter().any(|p| p.status.is_failure())
+    }
+}
+
+/// Streams day reports to `out` as they complete.
+///
+/// JSON is an array of one object per part and CSV has one row per part,
+/// both with the fields `day`, `part`, `input`, `title`, `answer`,
+/// `expected`, `status`, `time_ns` and `error`. JSON gives integer answers
+/// as numbers, of any size, and an unsolved part's answer as null.
+pub struct ReportWriter<W: Write> {
+    out: W,
+    format: Format,
+    show_time: bool,
+    rows: usize,
+}
+
+impl<W: Write> ReportWriter<W> {
+    pub fn new(out: W, format: Format, show_time: bool) -> io::Result<Self> {
+        let mut writer = Self {
+            out,
+            format,
+            show_time,
+            rows: 0,
+        };
+        match format {
+            Format::Text => {}
+            Format::Json => writeln!(writer.out, "[")?,
+            Format::Csv => writeln!(
+                writer.out,
+                "day,part,input,title,answer,expected,status,time_ns,error"
+            )?,
+        }
+        Ok(writer)
+    }
+
+    pub fn write(&mut self, report: &DayReport) -> io::Result<()> {
+        match self.format {
+            Format::Text => self.write_text(report)?,
+            Format::Json | Format::Csv => {
+                for row in rows(report) {
+                    self.write_row(&row)?;
+                }
+            }
+        }
+        self.out.flush()
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.format == Format::Json {
+            if self.rows > 0 {
+                writeln!(self.out)?;
+            }
+            writeln!(self.out, "]")?;
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+
+    fn write_text(&mut self, report: &DayReport) -> io::Result<()> {
+        let out = &mut self.out;
+        writeln!(out, "--- Day {}: {} ---", report.day, report.title)?;
+        if let Some(err) = &report.error {
+            writeln!(out, "Error: {err}")?;
+        }
+        for part in &report.parts {
+            // Only inputs other than the usual two are worth naming
+            let label = match part.input.as_str() {
+                "input" | "example" => part.part.to_string(),
+                input => format!("{} ({input})", part.part),
+            };
+            match (&part.answer, &part.status) {
+                (_, Status::Timeout) => writeln!(out, "{label}: TIMEOUT")?,
+                (Err(err), _) => writeln!(out, "{label}: error: {err}")?,
+                // No answer to show, so that text saying "unsolved" is
+                // never mistaken for one
+                (_, status @ Status::Unsolved) => {
+                    writeln!(out, "{label}: [{status}]")?
+                }
+                (Ok(answer), Status::Unchecked) => {
+                    writeln!(out, "{label}: {answer}")?
+                }
+                (Ok(answer), status) => {
+                    writeln!(out, "{label}: {answer} [{status}]")?
+                }
+            }
+        }
+        if self.show_time && !report.parts.is_empty() {
+            let times: Vec<Duration> =
+                report.parts.iter().map(|p| p.time).collect();
+            match times[..] {
+                [one, two] => writeln!(out, "Duration: {:?}", (one, two))?,
+                _ => writeln!(out, "Duration: {times:?}")?,
+            }
+        }
+        writeln!(out)
+    }
+
+    fn write_row(&mut self, row: &Row) -> io::Result<()> {
+        let out = &mut self.out;
+        match self.format {
+            Format::Json => {
+                if self.rows > 0 {
+                    writeln!(out, ",")?;
+                }
+                write!(
+                    out,
+                    "  {{\"day\": {}, \"part\": {}, \"input\": {}, \
+                     \"title\": {}, \"answer\": {}, \"expected\": {}, \
+                     \"status\": {}, \"time_ns\": {}, \"error\": {}}}",
+                    row.day,
+                    row.part,
+                    json_string(row.input),
+                    json_string(Some(row.title)),
+                    json_answer(row.answer),
+                    json_answer(row.expected),
+                    json_string(Some(row.status)),
+                    row.time_ns,
+                    json_string(row.error),
+                )?;
+            }
+            Format::Csv => writeln!(
+                out,
+                "{},{},{},{},{},{},{},{},{}",
+                row.day,
+                row.part,
+                csv_field(row.input.unwrap_or("")),
+                csv_field(row.title),
+                csv_field(&csv_answer(row.answer)),
+                csv_field(&csv_answer(row.expected)),
+                row.status,
+                row.time_ns,
+                csv_field(row.error.unwrap_or("")),
+            )?,
+            Format::Text => unreachable!("text is written per day"),
+        }
+        self.rows += 1;
+        Ok(())
+    }
+}
+
+struct Row<'a> {
+    day: u8,
+    part: u8,
+    input: Option<&'a str>,
+    title: &'a str,
+    answer: Option<&'a Answer>,
+    expected: Option<&'a Answer>,
+    status: &'static str,
+    time_ns: u128,
+    error: Option<&'a str>,
+}
+
+fn rows(report: &DayReport) -> Vec<Row<'_>> {
+    if let Some(err) = &report.error {
+        // Nothing was solved, so report the failure against both parts
+        return Part::ALL
+            .into_iter()
+            .map(|part| Row {
+                day: report.day,
+                part: part.number(),
+                input: None,
+                title: report.title,
+                answer: None,
+                expected: None,
+                status: Status::Error.name(),
+                time_ns: 0,
+                error: Some(err),
+            })
+            .collect();
+    }
+
+    report
+        .parts
+        .iter()
+        .map(|part| Row {
+            day: report.day,
+            part: part.part.number(),
+            input: Some(&part.input),
+            title: report.title,
+            answer: part.answer.as_ref().ok(),
+            expected: match &part.status {
+                Status::Fail { expected } => Some(expected),
+                Status::Pass => part.answer.as_ref().ok(),
+                _ => None,
+            },
+            status: part.status.name(),
+            time_ns: part.time.as_nanos(),
+            error: part.answer.as_ref().err().map(String::as_str),
+        })
+        .collect()
+}
+
+fn json_answer(answer: Option<&Answer>) -> String {
+    match answer {
+        Some(Answer::Text(text)) => json_string(Some(text)),
+        Some(Answer::Unsolved) | None => "null".to_string(),
+        Some(answer) => answer.to_string(),
+    }
+}
+
+fn json_string(value: Option<&str>) -> String {
+    let Some(value) = value else {
+        return "null".to_string();
+    };
+    let mut result = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                result.push_str(&format!("\\u{:04x}", c as u32))
+            }
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn csv_answer(answer: Option<&Answer>) -> String {
+    match answer {
+        Some(Answer::Unsolved) | None => String::new(),
+        Some(answer) => answer.to_string(),
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> DayReport {
+        DayReport {
+            day: 1,
+            title: "Secret Entrance",
+            error: None,
+            parts: vec![
+                PartReport {
+                    part: Part::One,
+                    input: "input".into(),
+                    answer: Ok(Answer::Int(3)),
+                    time: Duration::from_nanos(1500),
+                    status: Status::Pass,
+                },
+                PartReport {
+                    part: Part::Two,
+                    input: "example2".into(),
+                    answer: Err("day 1, line 2: \"bad\", input".into()),
+                    time: Duration::from_nanos(20),
+                    status: Status::Error,
+                },
+            ],
+        }
+    }
+
+    fn render(format: Format) -> String {
+        let mut writer = ReportWriter::new(Vec::new(), format, true).unwrap();
+        writer.write(&report()).unwrap();
+        String::from_utf8(writer.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            render(Format::Json),
+            "[\n  {\"day\": 1, \"part\": 1, \"input\": \"input\", \
+             \"title\": \"Secret Entrance\", \"answer\": 3, \
+             \"expected\": 3, \"status\": \"pass\", \"time_ns\": 1500, \
+             \"error\": null},\n  {\"day\": 1, \"part\": 2, \
+             \"input\": \"example2\", \"title\": \"Secret Entrance\", \
+             \"answer\": null, \"expected\": null, \"status\": \"error\", \
+             \"time_ns\": 20, \
+             \"error\": \"day 1, line 2: \\\"bad\\\", input\"}\n]\n"
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            render(Format::Csv),
+            "day,part,input,title,answer,expected,status,time_ns,error\n\
+             1,1,input,Secret Entrance,3,3,pass,1500,\n\
+             1,2,example2,Secret Entrance,,,error,20,\
+             \"day 1, line 2: \"\"bad\"\", input\"\n"
+        );
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            render(Format::Text),
+            "--- Day 1: Secret Entrance ---\n\
+             Part One: 3 [PASS]\n\
+             Part Two (example2): error: day 1, line 2: \"bad\", input\n\
+             Duration: (1.5µs, 20ns)\n\n"
+        );
+    }
+
+    #[test]
+    fn answer_types() {
+        let mut report = report();
+        report.parts[0].answer = Ok(Answer::from("a,\"b\""));
+        report.parts[0].status = Status::Unchecked;
+        report.parts[1].answer = Ok(Answer::Unsolved);
+        report.parts[1].status = Status::Unsolved;
+        let render = |format| {
+            let mut writer =
+                ReportWriter::new(Vec::new(), format, false).unwrap();
+            writer.write(&report).unwrap();
+            String::from_utf8(writer.finish().unwrap()).unwrap()
+        };
+
+        let json = render(Format::Json);
+        assert!(
+            json.contains("\"answer\": \"a,\\\"b\\\"\", \"expected\": null")
+        );
+        assert!(json.contains(
+            "\"answer\": null, \"expected\": null, \
+                               \"status\": \"unsolved\""
+        ));
+        assert!(render(Format::Csv).ends_with(",,unsolved,20,\n"));
+        assert!(render(Format::Text)
+            .contains("Part Two (example2): [UNSOLVED]\n"));
+    }
+}
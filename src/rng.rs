@@ -0,0 +1,93 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::BigUint;
+
+/// A small pseudo-random generator (xorshift64*), for sampling and tests
+/// rather than anything that must be unpredictable.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // An all-zero state would only ever produce zeroes
+        Self(if seed == 0 {
+            0x9e37_79b9_7f4a_7c15
+        } else {
+            seed
+        })
+    }
+
+    /// Seeded from the clock, for runs that should differ.
+    pub fn from_time() -> Self {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH);
+        Self::new(now.map_or(0, |now| now.as_nanos() as u64))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A number in `0..n`, each equally likely. Panics if `n` is zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "cannot pick below zero");
+        // Drawing again past the last whole multiple of `n` keeps the
+        // remainders even
+        let limit = u64::MAX - u64::MAX % n;
+        loop {
+            let value = self.next_u64();
+            if value < limit {
+                return value % n;
+            }
+        }
+    }
+
+    /// [`Rng::below`] for a number of any size. Panics if `n` is zero.
+    pub fn below_big(&mut self, n: &BigUint) -> BigUint {
+        if let Some(n) = n.to_u64() {
+            return BigUint::from(self.below(n));
+        }
+        // Random limbs cut to `n`'s bit length land below it at least half
+        // the time
+        let limbs = n.limbs();
+        let top = u32::MAX >> limbs[limbs.len() - 1].leading_zeros();
+        loop {
+            let mut value: Vec<u32> =
+                limbs.iter().map(|_| self.next_u64() as u32).collect();
+            *value.last_mut().unwrap() &= top;
+            let value = BigUint::from_limbs(value);
+            if value < *n {
+                return value;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn below() {
+        let mut rng = Rng::new(0);
+        assert_eq!(rng.below(1), 0);
+        let mut seen = [0; 3];
+        for _ in 0..30_000 {
+            seen[rng.below(3) as usize] += 1;
+        }
+        assert!(seen.iter().all(|&n| (9_000..11_000).contains(&n)));
+        assert!((0..100).all(|_| rng.below(u64::MAX) < u64::MAX));
+
+        let n = BigUint::from(3u64 << 62) * 5;
+        let mut low = 0;
+        for _ in 0..1000 {
+            let value = rng.below_big(&n);
+            assert!(value < n);
+            low += usize::from(value < 3u64 << 62);
+        }
+        assert!((100..300).contains(&low));
+        assert_eq!(rng.below_big(&BigUint::from(1u64)), 0);
+    }
+}
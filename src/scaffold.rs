@@ -0,0 +1,269 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{Error, Inputs, Result};
+
+// Module template; `__DAY__`, `__NN__` and `__TITLE__` are filled in
+const TEMPLATE: &str = r#"use super::YEAR;
+use crate::error::parse;
+use crate::{ParseError, Result, Solver};
+
+const DAY: u8 = __DAY__;
+
+fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse(DAY, input, line))
+        .collect()
+}
+
+pub fn part_one(numbers: &[i64]) -> Result<i64> {
+    Ok(numbers.iter().sum())
+}
+
+pub fn part_two(numbers: &[i64]) -> Result<i64> {
+    Ok(numbers.iter().product())
+}
+
+pub struct Day__NN__;
+
+impl Solver for Day__NN__ {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "__TITLE__";
+
+    type Input = Vec<i64>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<i64> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<i64> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    #[ignore = "example answers not filled in yet"]
+    fn example() -> Result<()> {
+        let input = parse_input(&read_example(YEAR, DAY)?)?;
+        assert_eq!(part_one(&input)?, 0);
+        assert_eq!(part_two(&input)?, 0);
+        Ok(())
+    }
+}
+"#;
+
+const EXPECTED_TEMPLATE: &str = "\
+# Answers the example should give, e.g.
+# part_one = 42
+";
+
+// Year module template; `__YEAR__` is filled in
+const YEAR_TEMPLATE: &str = "\
+//! Advent of Code __YEAR__.
+
+pub const YEAR: u16 = __YEAR__;
+
+";
+
+// Marks the start of the registry in lib.rs
+const REGISTRY_MARKER: &str = "// register puzzle here";
+
+/// Generates `src/yYYYY/dayNN.rs` under `root` for the year of `inputs`,
+/// registers it in the year module and `src/lib.rs` and creates empty
+/// example and expected-answer files, returning every path it wrote. The
+/// year module is created for a new year. Refuses to touch a day that
+/// already has a module.
+pub fn new_day(
+    root: &Path,
+    inputs: &Inputs,
+    day: u8,
+    title: &str,
+) -> Result<Vec<PathBuf>> {
+    let year = inputs.year();
+    let year_dir = root.join("src").join(format!("y{year}"));
+    let year_path = year_dir.join("mod.rs");
+    let module = format!("day{day:02}");
+    let module_path = year_dir.join(format!("{module}.rs"));
+    let lib_path = root.join("src").join("lib.rs");
+    let lib = read(&lib_path)?;
+    let new_year = !year_path.exists();
+    let year_module = if new_year {
+        YEAR_TEMPLATE.replace("__YEAR__", &year.to_string())
+    } else {
+        read(&year_path)?
+    };
+    if module_path.exists()
+        || year_module.contains(&format!("pub mod {module};"))
+    {
+        return Err(Error::Io {
+            path: module_path,
+            source: io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("day {day} of {year} already exists"),
+            ),
+        });
+    }
+
+    let cannot_register = |path: &Path| Error::Io {
+        path: path.to_path_buf(),
+        source: io::Error::other("cannot find where to register the day"),
+    };
+    let lib = register(&lib, year, day, new_year)
+        .ok_or_else(|| cannot_register(&lib_path))?;
+    let year_module = add_mod(&year_module, &format!("pub mod {module};"))
+        .ok_or_else(|| cannot_register(&year_path))?;
+    let source = TEMPLATE
+        .replace("__DAY__", &day.to_string())
+        .replace("__NN__", &format!("{day:02}"))
+        .replace("__TITLE__", &title.replace('"', "\\\""));
+    fs::create_dir_all(&year_dir).map_err(|source| Error::Io {
+        path: year_dir.clone(),
+        source,
+    })?;
+    write(&module_path, &source)?;
+    write(&year_path, &year_module)?;
+    write(&lib_path, &lib)?;
+
+    let mut written = vec![module_path, year_path, lib_path];
+    fs::create_dir_all(inputs.dir()).map_err(|source| Error::Io {
+        path: inputs.dir(),
+        source,
+    })?;
+    let example = inputs.path(day, "example");
+    let expected = example.with_extension("expected");
+    for (path, text) in [(example, ""), (expected, EXPECTED_TEMPLATE)] {
+        // Examples may have been saved before the module was generated
+        if !path.exists() {
+            write(&path, text)?;
+            written.push(path);
+        }
+    }
+    Ok(written)
+}
+
+// Adds the registry entry for `day` of `year`, and the year's `pub mod`
+// line when it is new, each after the last one that sorts before it so
+// both lists stay ordered
+fn register(lib: &str, year: u16, day: u8, new_year: bool) -> Option<String> {
+    let entry_line =
+        format!("    Puzzle::of::<y{year}::day{day:02}::Day{day:02}>(),");
+
+    let mut lines: Vec<String> = lib.lines().map(String::from).collect();
+    let marker = lines.iter().position(|l| l.trim() == REGISTRY_MARKER)?;
+    let entry_at = lines[marker + 1..]
+        .iter()
+        .take_while(|l| l.trim_start().starts_with("Puzzle::of::<"))
+        .take_while(|l| l.as_str() < entry_line.as_str())
+        .count()
+        + marker
+        + 1;
+    lines.insert(entry_at, entry_line);
+
+    let lib = lines.join("\n") + "\n";
+    if new_year {
+        add_mod(&lib, &format!("pub mod y{year};"))
+    } else {
+        Some(lib)
+    }
+}
+
+// Adds `mod_line` among the `pub mod` lines of `source`, in order. A file
+// without any gets it appended.
+fn add_mod(source: &str, mod_line: &str) -> Option<String> {
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    // Year modules sort apart from the others, by their `y` prefix
+    let prefix = &mod_line[..mod_line.find(|c: char| c.is_ascii_digit())?];
+    let is_peer = |l: &String| l.starts_with(prefix);
+    let mod_at = match lines
+        .iter()
+        .rposition(|l| is_peer(l) && l.as_str() < mod_line)
+    {
+        Some(i) => i + 1,
+        None => lines.iter().position(is_peer).unwrap_or(lines.len()),
+    };
+    lines.insert(mod_at, mod_line.to_string());
+    Some(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write(path: &Path, text: &str) -> Result<()> {
+    fs::write(path, text).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_in_order() {
+        let lib = "pub mod y2025;\n\n\
+                   static PUZZLES: &[Puzzle] = &[\n    \
+                   // register puzzle here\n    \
+                   Puzzle::of::<y2025::day01::Day01>(),\n    \
+                   Puzzle::of::<y2025::day03::Day03>(),\n];\n";
+        assert_eq!(
+            register(lib, 2025, 2, false).unwrap(),
+            "pub mod y2025;\n\n\
+             static PUZZLES: &[Puzzle] = &[\n    \
+             // register puzzle here\n    \
+             Puzzle::of::<y2025::day01::Day01>(),\n    \
+             Puzzle::of::<y2025::day02::Day02>(),\n    \
+             Puzzle::of::<y2025::day03::Day03>(),\n];\n"
+        );
+        assert_eq!(
+            register(lib, 2024, 1, true).unwrap(),
+            "pub mod y2024;\npub mod y2025;\n\n\
+             static PUZZLES: &[Puzzle] = &[\n    \
+             // register puzzle here\n    \
+             Puzzle::of::<y2024::day01::Day01>(),\n    \
+             Puzzle::of::<y2025::day01::Day01>(),\n    \
+             Puzzle::of::<y2025::day03::Day03>(),\n];\n"
+        );
+        assert_eq!(register("pub mod y2025;\n", 2025, 2, false), None);
+
+        let year = "pub const YEAR: u16 = 2025;\n\npub mod day01;\n\
+                    pub mod day03;\n";
+        assert_eq!(
+            add_mod(year, "pub mod day02;").unwrap(),
+            "pub const YEAR: u16 = 2025;\n\npub mod day01;\n\
+             pub mod day02;\npub mod day03;\n"
+        );
+        let new_year = YEAR_TEMPLATE.replace("__YEAR__", "2026");
+        assert!(add_mod(&new_year, "pub mod day01;")
+            .unwrap()
+            .ends_with("= 2026;\n\npub mod day01;\n"));
+    }
+
+    #[test]
+    fn refuses_existing_day() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let mut inputs = Inputs::new(root.join("inputs"));
+        inputs.set_year(2025);
+        let err = new_day(root, &inputs, 1, "Secret Entrance").unwrap_err();
+        assert!(err.to_string().ends_with("day 1 of 2025 already exists"));
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt::{self, Display};
+
+use crate::bench::{self, BenchConfig, DayBench};
+use crate::{Answer, Result};
+
+/// A single day's puzzle: its identity and the two parts.
+pub trait Solver {
+    const YEAR: u16;
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    /// The puzzle input once parsed, shared by both parts.
+    type Input;
+    /// Any integer, [`BigUint`](crate::BigUint) or string, or an
+    /// [`Answer`] itself.
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne>;
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo>;
+
+    /// The input drawn as a Graphviz DOT graph, for days where a picture
+    /// helps; None for the rest.
+    fn dot(_input: &Self::Input) -> Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        Part::ALL.into_iter().find(|part| part.number() == number)
+    }
+
+    /// Identifier used for this part in data files, e.g. `part_one`.
+    pub fn key(self) -> &'static str {
+        match self {
+            Part::One => "part_one",
+            Part::Two => "part_two",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Part> {
+        Part::ALL.into_iter().find(|part| part.key() == key)
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.write_str("Part One"),
+            Part::Two => f.write_str("Part Two"),
+        }
+    }
+}
+
+/// Type-erased [`Solver`], as stored in the registry.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    year: u16,
+    day: u8,
+    title: &'static str,
+    part_one: fn(&str) -> Result<Answer>,
+    part_two: fn(&str) -> Result<Answer>,
+    bench: fn(&str, &BenchConfig) -> Result<DayBench>,
+    dot: fn(&str) -> Result<Option<String>>,
+}
+
+impl Puzzle {
+    pub const fn of<S: Solver>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            part_one: part_one::<S>,
+            part_two: part_two::<S>,
+            bench: bench::run::<S>,
+            dot: dot::<S>,
+        }
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn title(&self) -> &'static str {
+        self.title
+    }
+
+    pub fn part_one(&self, input: &str) -> Result<Answer> {
+        (self.part_one)(input)
+    }
+
+    pub fn part_two(&self, input: &str) -> Result<Answer> {
+        (self.part_two)(input)
+    }
+
+    pub fn solve(&self, part: Part, input: &str) -> Result<Answer> {
+        match part {
+            Part::One => self.part_one(input),
+            Part::Two => self.part_two(input),
+        }
+    }
+
+    pub fn bench(
+        &self,
+        input: &str,
+        config: &BenchConfig,
+    ) -> Result<DayBench> {
+        (self.bench)(input, config)
+    }
+
+    /// The input as a Graphviz DOT graph, if the day can draw one.
+    pub fn dot(&self, input: &str) -> Result<Option<String>> {
+        (self.dot)(input)
+    }
+}
+
+fn part_one<S: Solver>(input: &str) -> Result<Answer> {
+    S::part_one(&S::parse(input)?).map(Into::into)
+}
+
+fn part_two<S: Solver>(input: &str) -> Result<Answer> {
+    S::part_two(&S::parse(input)?).map(Into::into)
+}
+
+fn dot<S: Solver>(input: &str) -> Result<Option<String>> {
+    S::dot(&S::parse(input)?)
+}
@@ -0,0 +1,350 @@
+/// Disjoint sets over `0..n`, joined by rank with path compression.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// `n` singleton sets.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of disjoint sets.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// The representative of the set holding `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // Point everything on the way up straight at the root
+        let mut x = x;
+        while self.parent[x] != root {
+            let next = self.parent[x];
+            self.parent[x] = root;
+            x = next;
+        }
+        root
+    }
+
+    /// Joins the sets of `x` and `y`, returning false if they were one.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut x, mut y) = (self.find(x), self.find(y));
+        if x == y {
+            return false;
+        }
+
+        if self.rank[x] < self.rank[y] {
+            (x, y) = (y, x);
+        }
+        if self.rank[x] == self.rank[y] {
+            self.rank[x] += 1;
+        }
+        self.parent[y] = x;
+        self.size[x] += self.size[y];
+        self.components -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// Size of the set holding `x`.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Size of every set, in order of their representatives.
+    pub fn sizes(&mut self) -> Vec<usize> {
+        let mut sizes = Vec::new();
+        for x in 0..self.len() {
+            if self.find(x) == x {
+                sizes.push(self.size[x]);
+            }
+        }
+        sizes
+    }
+
+    /// The elements in the set holding `x`, in increasing order.
+    pub fn members(&mut self, x: usize) -> Vec<usize> {
+        let root = self.find(x);
+        (0..self.len()).filter(|&y| self.find(y) == root).collect()
+    }
+
+    /// Every set as its elements in increasing order, ordered by their
+    /// smallest element.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if index[root] == usize::MAX {
+                index[root] = groups.len();
+                groups.push(Vec::new());
+            }
+            groups[index[root]].push(x);
+        }
+        groups
+    }
+}
+
+/// Disjoint sets whose unions can be undone, newest first.
+///
+/// There is no path compression, which would rewrite history; union by
+/// size keeps `find` logarithmic instead. Suits offline algorithms that
+/// explore a change and then take it back, such as divide and conquer over
+/// time.
+#[derive(Debug, Clone)]
+pub struct RollbackUnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+    // The root attached by each union, or None for a union that did nothing
+    history: Vec<Option<usize>>,
+}
+
+impl RollbackUnionFind {
+    /// `n` singleton sets.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    pub fn find(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Joins the sets of `x` and `y`, returning false if they were one.
+    /// Either way the call can be undone.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut x, mut y) = (self.find(x), self.find(y));
+        if x == y {
+            self.history.push(None);
+            return false;
+        }
+
+        if self.size[x] < self.size[y] {
+            (x, y) = (y, x);
+        }
+        self.parent[y] = x;
+        self.size[x] += self.size[y];
+        self.components -= 1;
+        self.history.push(Some(y));
+        true
+    }
+
+    pub fn same_set(&self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    pub fn size_of(&self, x: usize) -> usize {
+        self.size[self.find(x)]
+    }
+
+    /// The elements in the set holding `x`, in increasing order.
+    pub fn members(&self, x: usize) -> Vec<usize> {
+        let root = self.find(x);
+        (0..self.len()).filter(|&y| self.find(y) == root).collect()
+    }
+
+    /// A point to [`rollback`](Self::rollback) to.
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undoes the latest union, returning false if there is none.
+    pub fn undo(&mut self) -> bool {
+        let Some(entry) = self.history.pop() else {
+            return false;
+        };
+        if let Some(y) = entry {
+            let x = self.parent[y];
+            self.parent[y] = y;
+            self.size[x] -= self.size[y];
+            self.components += 1;
+        }
+        true
+    }
+
+    /// Undoes every union since `snapshot` was taken.
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+
+    fn below(rng: &mut Rng, n: usize) -> usize {
+        rng.below(n as u64) as usize
+    }
+
+    // The obvious quadratic version: a label per element, relabelled on
+    // every union
+    #[derive(Clone, PartialEq, Debug)]
+    struct Naive(Vec<usize>);
+
+    impl Naive {
+        fn union(&mut self, x: usize, y: usize) -> bool {
+            let (from, to) = (self.0[y], self.0[x]);
+            for label in &mut self.0 {
+                if *label == from {
+                    *label = to;
+                }
+            }
+            from != to
+        }
+
+        fn members(&self, x: usize) -> Vec<usize> {
+            (0..self.0.len())
+                .filter(|&y| self.0[y] == self.0[x])
+                .collect()
+        }
+
+        fn components(&self) -> usize {
+            let mut labels = self.0.clone();
+            labels.sort_unstable();
+            labels.dedup();
+            labels.len()
+        }
+    }
+
+    #[test]
+    fn example() {
+        let mut sets = UnionFind::new(5);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(3, 4));
+        assert!(!sets.union(1, 0));
+        assert!(sets.same_set(0, 1));
+        assert!(!sets.same_set(1, 2));
+        assert_eq!(sets.components(), 3);
+        assert_eq!(sets.members(4), [3, 4]);
+        assert_eq!(sets.groups(), [vec![0, 1], vec![2], vec![3, 4]]);
+        let mut sizes = sets.sizes();
+        sizes.sort_unstable();
+        assert_eq!(sizes, [1, 2, 2]);
+    }
+
+    #[test]
+    fn long_chain() {
+        // Deep enough to overflow the stack if `find` recursed
+        let n = 1_000_000;
+        let mut sets = UnionFind::new(n);
+        for x in 1..n {
+            sets.parent[x] = x - 1;
+        }
+        assert_eq!(sets.find(n - 1), 0);
+        assert_eq!(sets.parent[n - 1], 0);
+    }
+
+    #[test]
+    fn matches_naive() {
+        let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+        for round in 0..50 {
+            let n = 1 + round;
+            let mut sets = UnionFind::new(n);
+            let mut naive = Naive((0..n).collect());
+            for _ in 0..3 * n {
+                let (x, y) = (below(&mut rng, n), below(&mut rng, n));
+                assert_eq!(sets.union(x, y), naive.union(x, y));
+                let z = below(&mut rng, n);
+                assert_eq!(sets.same_set(x, z), naive.0[x] == naive.0[z]);
+                assert_eq!(sets.members(z), naive.members(z));
+                assert_eq!(sets.size_of(z), naive.members(z).len());
+                assert_eq!(sets.components(), naive.components());
+            }
+            let groups: usize = sets.groups().iter().map(Vec::len).sum();
+            assert_eq!(groups, n);
+        }
+    }
+
+    #[test]
+    fn rollback_matches_naive() {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        for round in 0..50 {
+            let n = 1 + round;
+            let mut sets = RollbackUnionFind::new(n);
+            let mut states = vec![(sets.snapshot(), Naive((0..n).collect()))];
+            for _ in 0..4 * n {
+                let (_, naive) = states.last().unwrap();
+                let mut naive = naive.clone();
+                if below(&mut rng, 3) == 0 && states.len() > 1 {
+                    // Return to a random earlier state
+                    states.truncate(1 + below(&mut rng, states.len() - 1));
+                    let (snapshot, earlier) = states.last().unwrap();
+                    sets.rollback(*snapshot);
+                    naive = earlier.clone();
+                } else {
+                    let (x, y) = (below(&mut rng, n), below(&mut rng, n));
+                    assert_eq!(sets.union(x, y), naive.union(x, y));
+                    states.push((sets.snapshot(), naive.clone()));
+                }
+
+                let z = below(&mut rng, n);
+                assert_eq!(sets.members(z), naive.members(z));
+                assert_eq!(sets.size_of(z), naive.members(z).len());
+                assert_eq!(sets.components(), naive.components());
+            }
+        }
+    }
+
+    #[test]
+    fn undo() {
+        let mut sets = RollbackUnionFind::new(3);
+        assert!(sets.union(0, 1));
+        assert!(!sets.union(1, 0));
+        assert!(sets.undo());
+        assert!(sets.same_set(0, 1));
+        assert!(sets.undo());
+        assert!(!sets.same_set(0, 1));
+        assert_eq!(sets.components(), 3);
+        assert!(!sets.undo());
+    }
+}
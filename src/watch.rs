@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Notices edits to a day's module and any of its `NN-*` input files by
+/// polling modification times, so files created later are picked up too.
+#[derive(Debug)]
+pub struct Watcher {
+    source: PathBuf,
+    inputs_dir: PathBuf,
+    prefix: String,
+    seen: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl Watcher {
+    pub fn new(source: &Path, inputs_dir: &Path, day: u8) -> Self {
+        let mut watcher = Self {
+            source: source.to_path_buf(),
+            inputs_dir: inputs_dir.to_path_buf(),
+            prefix: format!("{day:02}-"),
+            seen: BTreeMap::new(),
+        };
+        watcher.seen = watcher.scan();
+        watcher
+    }
+
+    /// Files currently watched.
+    pub fn files(&self) -> impl Iterator<Item = &Path> {
+        self.seen.keys().map(PathBuf::as_path)
+    }
+
+    /// Whether anything was modified, created or removed since last asked.
+    pub fn changed(&mut self) -> bool {
+        let current = self.scan();
+        let changed = current != self.seen;
+        self.seen = current;
+        changed
+    }
+
+    /// Blocks until [`Watcher::changed`] is true, checking every `interval`.
+    pub fn wait(&mut self, interval: Duration) {
+        while !self.changed() {
+            thread::sleep(interval);
+        }
+    }
+
+    fn scan(&self) -> BTreeMap<PathBuf, SystemTime> {
+        let mut paths = vec![self.source.clone()];
+        if let Ok(entries) = fs::read_dir(&self.inputs_dir) {
+            for entry in entries.flatten() {
+                let name = entry.file_name();
+                let ours = name
+                    .to_str()
+                    .is_some_and(|name| name.starts_with(&self.prefix));
+                if ours {
+                    paths.push(entry.path());
+                }
+            }
+        }
+
+        // A file that cannot be read is left out, so it counts as removed
+        paths
+            .into_iter()
+            .filter_map(|path| {
+                let modified = fs::metadata(&path).ok()?.modified().ok()?;
+                Some((path, modified))
+            })
+            .collect()
+    }
+}
+
+/// One part's result as read back from a `--format csv` run, which is how
+/// `aoc watch` learns what a freshly built binary produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+    pub expected: String,
+    pub status: String,
+    pub error: String,
+}
+
+impl Outcome {
+    /// Parses every row of CSV report `text`, skipping the header and any
+    /// row that does not have the report's columns.
+    pub fn parse_csv(text: &str) -> Vec<Self> {
+        csv_records(text)
+            .into_iter()
+            .skip(1)
+            .filter_map(|record| {
+                let [_, part, input, _, answer, expected, status, _, error] =
+                    <[String; 9]>::try_from(record).ok()?;
+                Some(Self {
+                    part: part.parse().ok()?,
+                    input,
+                    answer,
+                    expected,
+                    status,
+                    error,
+                })
+            })
+            .collect()
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self.status.as_str(), "fail" | "error" | "timeout")
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.is_failure() {
+            self.status.to_uppercase()
+        } else {
+            self.status.clone()
+        };
+        write!(f, "{} p{} {status}", self.input, self.part)?;
+        match self.status.as_str() {
+            "fail" => write!(f, " {} != {}", self.answer, self.expected),
+            "error" | "timeout" | "unsolved" => Ok(()),
+            _ if self.input == "input" => write!(f, " {}", self.answer),
+            _ => Ok(()),
+        }
+    }
+}
+
+// Splits CSV into records, undoing the quoting of fields that contain
+// commas, quotes or line breaks
+fn csv_records(text: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            (true, '"') => quoted = false,
+            (true, c) => field.push(c),
+            (false, '"') => quoted = true,
+            (false, ',') => record.push(mem::take(&mut field)),
+            (false, '\n') => {
+                record.push(mem::take(&mut field));
+                records.push(mem::take(&mut record));
+            }
+            (false, '\r') => {}
+            (false, c) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn notices_changes() {
+        let dir =
+            env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("day07.rs");
+        fs::write(&source, "").unwrap();
+        fs::write(dir.join("08-input.txt"), "").unwrap();
+
+        let mut watcher = Watcher::new(&source, &dir, 7);
+        assert_eq!(watcher.files().count(), 1);
+        assert!(!watcher.changed());
+
+        fs::write(dir.join("07-example.txt"), "1\n").unwrap();
+        assert!(watcher.changed());
+        assert_eq!(watcher.files().count(), 2);
+        assert!(!watcher.changed());
+
+        fs::remove_file(&source).unwrap();
+        assert!(watcher.changed());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn outcomes() {
+        let csv =
+            "day,part,input,title,answer,expected,status,time_ns,error\n\
+                   1,1,example,Secret Entrance,3,3,pass,1500,\n\
+                   1,2,example2,Secret Entrance,4,6,fail,20,\n\
+                   1,1,input,Secret Entrance,,,error,20,\
+                   \"day 1, line 2: \"\"bad\"\", input\"\n\
+                   1,2,input,Secret Entrance,99,,unknown,20,\n";
+        let outcomes = Outcome::parse_csv(csv);
+        let shown: Vec<String> =
+            outcomes.iter().map(Outcome::to_string).collect();
+        assert_eq!(
+            shown,
+            [
+                "example p1 pass",
+                "example2 p2 FAIL 4 != 6",
+                "input p1 ERROR",
+                "input p2 unknown 99",
+            ]
+        );
+        assert_eq!(outcomes[2].error, "day 1, line 2: \"bad\", input");
+        assert!(Outcome::parse_csv("Error: no input\n").is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+use super::YEAR;
+use crate::error::parse;
+use crate::{ParseError, Result, Solver};
+
+const DAY: u8 = 1;
+const DIAL_SIZE: i32 = 100;
+
+fn parse_input(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+    let error = |text, message| ParseError::locate(DAY, input, text, message);
+
+    // Parse direction as sign: R=+1, L=-1
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (sign, steps) = match line.split_at_checked(1) {
+                Some(("R", steps)) => (1, steps),
+                Some(("L", steps)) => (-1, steps),
+                _ => return Err(error(line, "expected `L` or `R` rotation")),
+            };
+            Ok((sign, parse(DAY, input, steps)?))
+        })
+        .collect()
+}
+
+pub fn part_one(instructions: &[(i32, i32)]) -> Result<i32> {
+    let mut position = 50;
+    let mut count = 0;
+
+    for &(sign, steps) in instructions {
+        position = (position + sign * steps).rem_euclid(DIAL_SIZE);
+        if position == 0 {
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+pub fn part_two(instructions: &[(i32, i32)]) -> Result<i32> {
+    let mut position = 50;
+    let mut count = 0;
+
+    for &(sign, steps) in instructions {
+        // Each full rotation crosses 0 once
+        count += steps / DIAL_SIZE;
+
+        let remainder = steps % DIAL_SIZE;
+        let new_position =
+            (position + sign * remainder).rem_euclid(DIAL_SIZE);
+
+        // Check if we crossed 0 (not counting starting from 0)
+        let crossed = if position != 0 {
+            if sign > 0 {
+                position + remainder >= DIAL_SIZE
+            } else {
+                position < remainder
+            }
+        } else {
+            false
+        };
+
+        // Landing on 0 counts (but not if we started from 0 and didn't move)
+        let landed_on_zero =
+            new_position == 0 && (position != 0 || remainder > 0);
+
+        if crossed || landed_on_zero {
+            count += 1;
+        }
+
+        position = new_position;
+    }
+
+    Ok(count)
+}
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Secret Entrance";
+
+    type Input = Vec<(i32, i32)>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<i32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<i32> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn example() -> Result<()> {
+        let input = parse_input(&read_example(YEAR, 1)?)?;
+        assert_eq!(part_one(&input)?, 3);
+        assert_eq!(part_two(&input)?, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse_input("L68\nX30\nR4x\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 1, line 2, column 1: expected `L` or `R` rotation `X30`"
+        );
+        let err = parse_input("L68\nR4x\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 1, line 2, column 2: invalid number `4x`"
+        );
+    }
+}
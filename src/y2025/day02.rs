@@ -0,0 +1,214 @@
+use super::YEAR;
+use crate::error::parse;
+use crate::{BigUint, IntervalSet, ParseError, Result, Solver};
+
+const DAY: u8 = 2;
+
+fn parse_input(input: &str) -> Result<IntervalSet<u64>, ParseError> {
+    let error = |text, message| ParseError::locate(DAY, input, text, message);
+
+    input
+        .trim()
+        .split(',')
+        .map(|range| {
+            let (start, end) = range
+                .split_once('-')
+                .ok_or_else(|| error(range, "expected `start-end`"))?;
+            let (start, end) =
+                (parse(DAY, input, start)?, parse(DAY, input, end)?);
+            // The set would drop it without a word
+            if start > end {
+                return Err(error(range, "range ends before it starts"));
+            }
+            Ok((start, end))
+        })
+        .collect()
+}
+
+// Generate all doubled numbers within a range
+fn find_doubled_in_range(start: u64, end: u64) -> Vec<u64> {
+    let mut result = Vec::new();
+
+    // Determine the digit lengths we need to check
+    let start_digits = start.to_string().len();
+    let end_digits = end.to_string().len();
+
+    for total_digits in start_digits..=end_digits {
+        if !total_digits.is_multiple_of(2) {
+            continue;
+        }
+
+        let half_digits = total_digits / 2;
+        let min_half = 10u64.pow((half_digits - 1) as u32);
+        let max_half = 10u64.pow(half_digits as u32) - 1;
+
+        // A doubled number is its half times 10..01
+        let unit = repeat_unit(half_digits, 2);
+        result
+            .extend(multiples_in_range(unit, min_half, max_half, start, end));
+    }
+
+    result
+}
+
+// The number that repeats a pattern of `pattern_len` digits `count` times
+// when multiplied by it, e.g. 10101 for two digits three times, or None if
+// it does not fit in a u64, when no repeat of that length does
+fn repeat_unit(pattern_len: usize, count: usize) -> Option<u64> {
+    let multiplier = 10u64.checked_pow(pattern_len as u32)?;
+    let mut unit = 0u64;
+    for _ in 0..count {
+        unit = unit.checked_mul(multiplier)?.checked_add(1)?;
+    }
+    Some(unit)
+}
+
+// Every `pattern * unit` in `start..=end`, for patterns from `min` to `max`;
+// only those in range are built, so none can overflow
+fn multiples_in_range(
+    unit: Option<u64>,
+    min: u64,
+    max: u64,
+    start: u64,
+    end: u64,
+) -> Vec<u64> {
+    let Some(unit) = unit else {
+        return Vec::new();
+    };
+    let first = min.max(start.div_ceil(unit));
+    let last = max.min(end / unit);
+    (first..=last).map(|pattern| pattern * unit).collect()
+}
+
+pub fn part_one(ranges: &IntervalSet<u64>) -> Result<BigUint> {
+    let mut sum = BigUint::zero();
+
+    // Ranges are merged, so no ID is counted twice
+    for (start, end) in ranges.iter() {
+        for doubled in find_doubled_in_range(start, end) {
+            sum += doubled;
+        }
+    }
+
+    Ok(sum)
+}
+
+// Generate all repeated-pattern numbers within a range (pattern repeated at least twice)
+fn find_repeated_in_range(start: u64, end: u64) -> Vec<u64> {
+    let mut result = Vec::new();
+
+    let start_digits = start.to_string().len();
+    let end_digits = end.to_string().len();
+
+    for total_digits in start_digits..=end_digits {
+        // Try each possible pattern length (1 to total_digits/2)
+        for pattern_len in 1..=total_digits / 2 {
+            if !total_digits.is_multiple_of(pattern_len) {
+                continue;
+            }
+
+            let repeat_count = total_digits / pattern_len;
+            if repeat_count < 2 {
+                continue;
+            }
+
+            // Generate all patterns of this length
+            let min_pattern = if pattern_len == 1 {
+                1
+            } else {
+                10u64.pow((pattern_len - 1) as u32)
+            };
+            let max_pattern = 10u64.pow(pattern_len as u32) - 1;
+
+            let unit = repeat_unit(pattern_len, repeat_count);
+            result.extend(multiples_in_range(
+                unit,
+                min_pattern,
+                max_pattern,
+                start,
+                end,
+            ));
+        }
+    }
+
+    // Remove duplicates and sort
+    result.sort();
+    result.dedup();
+    result
+}
+
+pub fn part_two(ranges: &IntervalSet<u64>) -> Result<BigUint> {
+    let mut sum = BigUint::zero();
+
+    // Ranges are merged, so no ID is counted twice
+    for (start, end) in ranges.iter() {
+        for repeated in find_repeated_in_range(start, end) {
+            sum += repeated;
+        }
+    }
+
+    Ok(sum)
+}
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Gift Shop";
+
+    type Input = IntervalSet<u64>;
+    type PartOne = BigUint;
+    type PartTwo = BigUint;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<BigUint> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<BigUint> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn test_find_doubled() {
+        assert_eq!(find_doubled_in_range(11, 22), vec![11, 22]);
+        assert_eq!(find_doubled_in_range(95, 115), vec![99]);
+        assert_eq!(find_doubled_in_range(998, 1012), vec![1010]);
+        // Twenty digits, where most candidates would overflow a u64
+        let (start, top) = (u64::MAX - 10_000_000_000, 18446744071844674407);
+        assert_eq!(find_doubled_in_range(start, u64::MAX), vec![top]);
+        assert_eq!(find_repeated_in_range(start, u64::MAX), vec![top]);
+    }
+
+    #[test]
+    fn example() -> Result<()> {
+        let input = parse_input(&read_example(YEAR, 2)?)?;
+        assert_eq!(part_one(&input)?, 1227775554);
+        assert_eq!(part_two(&input)?, 4174379265);
+        Ok(())
+    }
+
+    #[test]
+    fn overlapping_ranges() -> Result<()> {
+        // 22 and 33 lie in both ranges but count once
+        let input = parse_input("11-33,22-44\n")?;
+        assert_eq!(part_one(&input)?, 11 + 22 + 33 + 44);
+
+        let err = parse_input("11-22,5-3\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 2, line 1, column 7: range ends before it starts `5-3`"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use super::YEAR;
+use crate::error::expect_chars;
+use crate::{ParseError, Result, Solver};
+
+const DAY: u8 = 3;
+
+fn parse_input(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|bank| {
+            expect_chars(DAY, input, bank, "0123456789")?;
+            Ok(bank.bytes().map(|b| b - b'0').collect())
+        })
+        .collect()
+}
+
+fn max_two_digit(digits: &[u8]) -> u32 {
+    let n = digits.len();
+    let mut max_val = 0;
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let val = digits[i] as u32 * 10 + digits[j] as u32;
+            max_val = max_val.max(val);
+        }
+    }
+
+    max_val
+}
+
+pub fn part_one(banks: &[Vec<u8>]) -> Result<u32> {
+    Ok(banks.iter().map(|bank| max_two_digit(bank)).sum())
+}
+
+fn max_n_digits(digits: &[u8], n: usize) -> u64 {
+    let len = digits.len();
+    if n > len {
+        return 0;
+    }
+
+    // Greedy: for each position, pick the largest available digit
+    // that still leaves enough digits remaining for the rest
+    let mut result = 0u64;
+    let mut start = 0;
+
+    for i in 0..n {
+        let remaining_picks = n - i - 1;
+        let end = len - remaining_picks;
+
+        // Find the largest digit in range [start, end)
+        let mut best_idx = start;
+        for j in start..end {
+            if digits[j] > digits[best_idx] {
+                best_idx = j;
+            }
+        }
+
+        result = result * 10 + digits[best_idx] as u64;
+        start = best_idx + 1;
+    }
+
+    result
+}
+
+pub fn part_two(banks: &[Vec<u8>]) -> Result<u64> {
+    Ok(banks.iter().map(|bank| max_n_digits(bank, 12)).sum())
+}
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Lobby";
+
+    type Input = Vec<Vec<u8>>;
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<u64> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn example() -> Result<()> {
+        let input = parse_input(&read_example(YEAR, 3)?)?;
+        assert_eq!(part_one(&input)?, 357);
+        assert_eq!(part_two(&input)?, 3121910778619);
+        Ok(())
+    }
+}
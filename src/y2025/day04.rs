@@ -0,0 +1,79 @@
+use super::YEAR;
+use crate::{Grid, ParseError, Pos, Result, Solver};
+
+const DAY: u8 = 4;
+
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(DAY, input, "@.")
+}
+
+fn count_adjacent_rolls(grid: &Grid<char>, pos: Pos) -> usize {
+    grid.neighbours8(pos).filter(|&n| grid[n] == '@').count()
+}
+
+// Rolls with fewer than four rolls around them
+fn accessible(grid: &Grid<char>) -> impl Iterator<Item = Pos> + '_ {
+    grid.find_all(&'@')
+        .filter(|&pos| count_adjacent_rolls(grid, pos) < 4)
+}
+
+pub fn part_one(grid: &Grid<char>) -> Result<usize> {
+    Ok(accessible(grid).count())
+}
+
+pub fn part_two(grid: &Grid<char>) -> Result<usize> {
+    let mut grid = grid.clone();
+    let mut total_removed = 0;
+
+    loop {
+        let to_remove: Vec<Pos> = accessible(&grid).collect();
+        if to_remove.is_empty() {
+            break;
+        }
+
+        for &pos in &to_remove {
+            grid[pos] = '.';
+        }
+        total_removed += to_remove.len();
+    }
+
+    Ok(total_removed)
+}
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Printing Department";
+
+    type Input = Grid<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<usize> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn example() -> Result<()> {
+        let input = parse_input(&read_example(YEAR, 4)?)?;
+        assert_eq!(part_one(&input)?, 13);
+        assert_eq!(part_two(&input)?, 43);
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use super::YEAR;
+use crate::error::parse;
+use crate::{IntervalSet, ParseError, Result, Solver};
+
+const DAY: u8 = 5;
+
+// Fresh ID ranges and the available IDs
+type Inventory = (IntervalSet<u64>, Vec<u64>);
+
+fn parse_input(input: &str) -> Result<Inventory, ParseError> {
+    let error = |text, message| ParseError::locate(DAY, input, text, message);
+
+    let (ranges, ids) = input.split_once("\n\n").ok_or_else(|| {
+        error(&input[input.len()..], "expected a blank line before IDs")
+    })?;
+
+    let ranges = ranges
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (start, end) = line
+                .split_once('-')
+                .ok_or_else(|| error(line, "expected `start-end`"))?;
+            let (start, end) =
+                (parse(DAY, input, start)?, parse(DAY, input, end)?);
+            // The set would drop it without a word
+            if start > end {
+                return Err(error(line, "range ends before it starts"));
+            }
+            Ok((start, end))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let ids = ids
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse(DAY, input, line))
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok((ranges, ids))
+}
+
+pub fn part_one((fresh, ids): &Inventory) -> Result<usize> {
+    Ok(ids.iter().filter(|&&id| fresh.contains(id)).count())
+}
+
+pub fn part_two((fresh, _): &Inventory) -> Result<u128> {
+    // Overlapping ranges were merged as they were parsed
+    Ok(fresh.len())
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Cafeteria";
+
+    type Input = Inventory;
+    type PartOne = usize;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<u128> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn example() -> Result<()> {
+        let input = parse_input(&read_example(YEAR, 5)?)?;
+        assert_eq!(part_one(&input)?, 3);
+        assert_eq!(part_two(&input)?, 14);
+        Ok(())
+    }
+
+    #[test]
+    fn reversed_range() {
+        let err = parse_input("3-5\n10-8\n\n4\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 5, line 2, column 1: range ends before it starts `10-8`"
+        );
+    }
+}
@@ -1,21 +1,41 @@
-fn parse_input(input: &str) -> Vec<(char, Vec<u64>)> {
+use super::YEAR;
+use crate::error::{expect_chars, parse};
+use crate::{ParseError, Result, Solver};
+
+const DAY: u8 = 6;
+
+type Problem = (char, Vec<u64>);
+
+// The worksheet read as rows (part one) and as right-to-left columns
+type Worksheet = (Vec<Problem>, Vec<Problem>);
+
+fn parse_input(input: &str) -> Result<Worksheet, ParseError> {
+    Ok((parse_rows(input)?, parse_columns(input)?))
+}
+
+// Worksheets are plain ASCII, so byte columns and character columns agree
+fn worksheet_lines(input: &str) -> Result<Vec<&str>, ParseError> {
     let lines: Vec<&str> = input.lines().collect();
+    for line in &lines {
+        expect_chars(DAY, input, line, "0123456789+* ")?;
+    }
+    Ok(lines)
+}
+
+fn parse_rows(input: &str) -> Result<Vec<Problem>, ParseError> {
+    let lines = worksheet_lines(input)?;
     if lines.is_empty() {
-        return vec![];
+        return Ok(vec![]);
     }
 
     let max_len = lines.iter().map(|l| l.len()).max().unwrap_or(0);
-    let padded: Vec<String> = lines
-        .iter()
-        .map(|l| format!("{:width$}", l, width = max_len))
-        .collect();
 
     let mut problems = Vec::new();
     let mut col = 0;
 
     while col < max_len {
         // Skip separator columns (all spaces)
-        if padded.iter().all(|line| {
+        if lines.iter().all(|line| {
             line.chars().nth(col).map(|c| c == ' ').unwrap_or(true)
         }) {
             col += 1;
@@ -25,7 +45,7 @@ fn parse_input(input: &str) -> Vec<(char, Vec<u64>)> {
         // Find the end of this problem (next all-space column or end)
         let mut end_col = col + 1;
         while end_col < max_len {
-            if padded.iter().all(|line| {
+            if lines.iter().all(|line| {
                 line.chars().nth(end_col).map(|c| c == ' ').unwrap_or(true)
             }) {
                 break;
@@ -37,16 +57,13 @@ fn parse_input(input: &str) -> Vec<(char, Vec<u64>)> {
         let mut numbers = Vec::new();
         let mut op = '+';
 
-        for line in &padded {
-            let segment: String =
-                line.chars().skip(col).take(end_col - col).collect();
+        for line in &lines {
+            let segment = &line[col.min(line.len())..end_col.min(line.len())];
             let trimmed = segment.trim();
             if trimmed == "+" || trimmed == "*" {
-                op = trimmed.chars().next().unwrap();
+                op = if trimmed == "+" { '+' } else { '*' };
             } else if !trimmed.is_empty() {
-                if let Ok(n) = trimmed.parse::<u64>() {
-                    numbers.push(n);
-                }
+                numbers.push(parse(DAY, input, trimmed)?);
             }
         }
 
@@ -57,7 +74,7 @@ fn parse_input(input: &str) -> Vec<(char, Vec<u64>)> {
         col = end_col;
     }
 
-    problems
+    Ok(problems)
 }
 
 fn solve_problem(op: char, numbers: &[u64]) -> u64 {
@@ -68,18 +85,17 @@ fn solve_problem(op: char, numbers: &[u64]) -> u64 {
     }
 }
 
-pub fn part_one(input: &str) -> u64 {
-    let problems = parse_input(input);
-    problems
+pub fn part_one((problems, _): &Worksheet) -> Result<u64> {
+    Ok(problems
         .iter()
         .map(|(op, nums)| solve_problem(*op, nums))
-        .sum()
+        .sum())
 }
 
-fn parse_input_v2(input: &str) -> Vec<(char, Vec<u64>)> {
-    let lines: Vec<&str> = input.lines().collect();
+fn parse_columns(input: &str) -> Result<Vec<Problem>, ParseError> {
+    let lines = worksheet_lines(input)?;
     if lines.is_empty() {
-        return vec![];
+        return Ok(vec![]);
     }
 
     let max_len = lines.iter().map(|l| l.len()).max().unwrap_or(0);
@@ -136,7 +152,13 @@ fn parse_input_v2(input: &str) -> Vec<(char, Vec<u64>)> {
                 })
                 .collect();
             if !num_str.is_empty() {
-                numbers.push(num_str.parse::<u64>().unwrap());
+                let top = padded.iter().position(|row| row[c] != ' ');
+                let number = num_str.parse().map_err(|_| {
+                    let line = top.map_or(0, |row| row + 1);
+                    let message = "number too large";
+                    ParseError::new(DAY, line, c + 1, &num_str, message)
+                })?;
+                numbers.push(number);
             }
         }
 
@@ -147,15 +169,38 @@ fn parse_input_v2(input: &str) -> Vec<(char, Vec<u64>)> {
         col = start_col;
     }
 
-    problems
+    Ok(problems)
 }
 
-pub fn part_two(input: &str) -> u64 {
-    let problems = parse_input_v2(input);
-    problems
+pub fn part_two((_, problems): &Worksheet) -> Result<u64> {
+    Ok(problems
         .iter()
         .map(|(op, nums)| solve_problem(*op, nums))
-        .sum()
+        .sum())
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Trash Compactor";
+
+    type Input = Worksheet;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<u64> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<u64> {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -164,9 +209,10 @@ mod tests {
     use crate::read_example;
 
     #[test]
-    fn example() {
-        let input = read_example(6);
-        assert_eq!(part_one(&input), 4277556);
-        assert_eq!(part_two(&input), 3263827);
+    fn example() -> Result<()> {
+        let input = parse_input(&read_example(YEAR, 6)?)?;
+        assert_eq!(part_one(&input)?, 4277556);
+        assert_eq!(part_two(&input)?, 3263827);
+        Ok(())
     }
 }
@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+
+use super::YEAR;
+use crate::{BigUint, Grid, ParseError, Result, Solver};
+
+const DAY: u8 = 7;
+
+// The manifold grid and the column of the beam's start
+type Manifold = (Grid<char>, usize);
+
+fn parse_input(input: &str) -> Result<Manifold, ParseError> {
+    let grid = Grid::parse(DAY, input, ".^S")?;
+
+    // Find S position (column)
+    let first = input.lines().find(|line| !line.is_empty()).unwrap_or(input);
+    let start_col = match grid.find(&'S') {
+        Some((0, col)) => col,
+        _ => {
+            let message = "expected `S` on the first row";
+            return Err(ParseError::locate(DAY, input, first, message));
+        }
+    };
+
+    Ok((grid, start_col))
+}
+
+pub fn part_one(&(ref grid, start_col): &Manifold) -> Result<usize> {
+    let rows = grid.height();
+    let cols = grid.width();
+
+    // Track active beams as (row, col) positions
+    let mut beams: HashSet<(usize, usize)> = HashSet::new();
+    beams.insert((0, start_col));
+
+    let mut split_count = 0;
+
+    loop {
+        let mut new_beams: HashSet<(usize, usize)> = HashSet::new();
+
+        for &(row, col) in &beams {
+            let next_row = row + 1;
+            if next_row >= rows {
+                continue; // Beam exits the manifold
+            }
+
+            let cell = grid[(next_row, col)];
+            if cell == '^' {
+                // Split: emit beams to left and right
+                split_count += 1;
+                if col > 0 {
+                    new_beams.insert((next_row, col - 1));
+                }
+                if col + 1 < cols {
+                    new_beams.insert((next_row, col + 1));
+                }
+            } else {
+                // Continue downward
+                new_beams.insert((next_row, col));
+            }
+        }
+
+        if new_beams.is_empty() {
+            break;
+        }
+        beams = new_beams;
+    }
+
+    Ok(split_count)
+}
+
+use std::collections::HashMap;
+
+// The timelines from the start, summed with `add`, or None once a sum does
+// not fit
+fn count_timelines<T: Default>(
+    grid: &Grid<char>,
+    start_col: usize,
+    one: T,
+    add: impl Fn(&T, &T) -> Option<T>,
+) -> Option<T> {
+    let rows = grid.height();
+    let cols = grid.width();
+    let merge = |beams: &mut HashMap<(usize, usize), T>, pos, count: &T| {
+        let slot = beams.entry(pos).or_default();
+        *slot = add(slot, count)?;
+        Some(())
+    };
+
+    // Track beams with timeline counts: position -> number of timelines at that position
+    let mut beams: HashMap<(usize, usize), T> = HashMap::new();
+    beams.insert((0, start_col), one);
+
+    let mut total_timelines = T::default();
+
+    loop {
+        let mut new_beams: HashMap<(usize, usize), T> = HashMap::new();
+
+        for (&(row, col), count) in &beams {
+            let next_row = row + 1;
+            if next_row >= rows {
+                // Beam exits - these timelines are complete
+                total_timelines = add(&total_timelines, count)?;
+                continue;
+            }
+
+            let cell = grid[(next_row, col)];
+            if cell == '^' {
+                // Split: each timeline becomes two timelines
+                if col > 0 {
+                    merge(&mut new_beams, (next_row, col - 1), count)?;
+                }
+                if col + 1 < cols {
+                    merge(&mut new_beams, (next_row, col + 1), count)?;
+                }
+            } else {
+                // Continue downward
+                merge(&mut new_beams, (next_row, col), count)?;
+            }
+        }
+
+        if new_beams.is_empty() {
+            break;
+        }
+        beams = new_beams;
+    }
+
+    Some(total_timelines)
+}
+
+pub fn part_two(&(ref grid, start_col): &Manifold) -> Result<BigUint> {
+    // Counts in u64 unless they overflow, and only then start over in
+    // BigUint
+    let total =
+        count_timelines(grid, start_col, 1u64, |a, b| a.checked_add(*b))
+            .map(BigUint::from)
+            .or_else(|| {
+                let one = BigUint::from(1u64);
+                count_timelines(grid, start_col, one, |a, b| Some(a + b))
+            });
+    Ok(total.expect("BigUint sums cannot overflow"))
+}
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Laboratories";
+
+    type Input = Manifold;
+    type PartOne = usize;
+    type PartTwo = BigUint;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<BigUint> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn example() -> Result<()> {
+        let input = parse_input(&read_example(YEAR, 7)?)?;
+        assert_eq!(part_one(&input)?, 21);
+        assert_eq!(part_two(&input)?, 40);
+        Ok(())
+    }
+
+    #[test]
+    fn past_u64() -> Result<()> {
+        // 70 rows of splitters, each doubling the timelines
+        let width = 201;
+        let mut input = format!("{:.^width$}\n", "S");
+        for _ in 0..70 {
+            input +=
+                &format!("{}\n{}\n", "^".repeat(width), ".".repeat(width));
+        }
+        let (grid, start_col) = parse_input(&input)?;
+        let small =
+            count_timelines(&grid, start_col, 1u64, |a, b| a.checked_add(*b));
+        assert_eq!(small, None);
+        let expected = (0..70).fold(BigUint::from(1u64), |n, _| n * 2);
+        assert_eq!(part_two(&(grid, start_col))?, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,213 @@
+use std::cmp::Reverse;
+
+use super::YEAR;
+use crate::error::parse;
+use crate::{Answer, BigUint, Dot, ParseError, Result, Solver, UnionFind};
+
+const DAY: u8 = 8;
+
+// How many of the closest pairs part one connects
+const CONNECTIONS: usize = 1000;
+
+// Part one's connections, or every pair if there are fewer
+fn connections(points: &[(i64, i64, i64)]) -> usize {
+    let n = points.len();
+    CONNECTIONS.min(n * n.saturating_sub(1) / 2)
+}
+
+fn parse_input(input: &str) -> Result<Vec<(i64, i64, i64)>, ParseError> {
+    let error = |text, message| ParseError::locate(DAY, input, text, message);
+
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let nums: Vec<i64> = line
+                .split(',')
+                .map(|n| parse(DAY, input, n))
+                .collect::<Result<_, _>>()?;
+            match nums[..] {
+                [x, y, z] => Ok((x, y, z)),
+                _ => Err(error(line, "expected `x,y,z`")),
+            }
+        })
+        .collect()
+}
+
+fn distance_squared(a: &(i64, i64, i64), b: &(i64, i64, i64)) -> i64 {
+    let dx = a.0 - b.0;
+    let dy = a.1 - b.1;
+    let dz = a.2 - b.2;
+    dx * dx + dy * dy + dz * dz
+}
+
+// Every pair of boxes as (distance squared, i, j), closest first
+fn closest_pairs(points: &[(i64, i64, i64)]) -> Vec<(i64, usize, usize)> {
+    let n = points.len();
+
+    // Calculate all pairwise distances
+    let mut edges: Vec<(i64, usize, usize)> = Vec::new();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let dist = distance_squared(&points[i], &points[j]);
+            edges.push((dist, i, j));
+        }
+    }
+
+    // Sort by distance
+    edges.sort_by_key(|e| e.0);
+    edges
+}
+
+// Connects the closest `connections` pairs, returning the circuits and the
+// pairs joined
+fn connect(
+    points: &[(i64, i64, i64)],
+    connections: usize,
+) -> (UnionFind, Vec<(usize, usize)>) {
+    let mut uf = UnionFind::new(points.len());
+    let pairs = closest_pairs(points);
+    let joined: Vec<(usize, usize)> = (pairs.into_iter())
+        .take(connections)
+        .map(|(_, i, j)| (i, j))
+        .collect();
+    for &(i, j) in &joined {
+        uf.union(i, j);
+    }
+    (uf, joined)
+}
+
+fn solve(points: &[(i64, i64, i64)], connections: usize) -> Result<BigUint> {
+    let (mut uf, _) = connect(points, connections);
+
+    // Get circuit sizes and multiply top 3
+    let mut sizes = uf.sizes();
+    sizes.sort_by(|a, b| b.cmp(a));
+
+    Ok(sizes.iter().take(3).map(|&s| BigUint::from(s)).product())
+}
+
+pub fn part_one(points: &[(i64, i64, i64)]) -> Result<BigUint> {
+    solve(points, connections(points))
+}
+
+pub fn part_two(points: &[(i64, i64, i64)]) -> Result<Answer> {
+    // Connect until all in one circuit
+    let mut uf = UnionFind::new(points.len());
+
+    for (_, i, j) in closest_pairs(points) {
+        if uf.union(i, j) && uf.components() == 1 {
+            // This was the last connection needed; the product of two
+            // `i64`s always fits an `i128`
+            let product = i128::from(points[i].0) * i128::from(points[j].0);
+            return Ok(Answer::from(product));
+        }
+    }
+
+    // Fewer than two boxes never need connecting
+    Ok(Answer::Unsolved)
+}
+
+/// The circuits after `connections` connections as Graphviz DOT, each
+/// boxed in with the connections inside it, largest first. Boxes left on
+/// their own are not drawn.
+pub fn dot(points: &[(i64, i64, i64)], connections: usize) -> String {
+    let (mut uf, joined) = connect(points, connections);
+    let mut circuits = uf.groups();
+    circuits.retain(|circuit| circuit.len() > 1);
+    circuits.sort_by_key(|circuit| Reverse(circuit.len()));
+
+    let mut dot = Dot::graph("day08");
+    let alone = points.len() - circuits.iter().map(Vec::len).sum::<usize>();
+    dot.comment(&format!(
+        "connections: {}, circuits: {}, boxes alone: {alone}",
+        joined.len(),
+        circuits.len()
+    ));
+    dot.defaults("node", &[("shape", "box")]);
+    for (index, circuit) in circuits.iter().enumerate() {
+        dot.begin(&format!("cluster_{index}"));
+        dot.set("label", &format!("circuit of {}", circuit.len()));
+        for &i in circuit {
+            let (x, y, z) = points[i];
+            dot.node(&i.to_string(), &[("label", &format!("{x},{y},{z}"))]);
+        }
+        let root = uf.find(circuit[0]);
+        for &(i, j) in &joined {
+            if uf.find(i) == root {
+                dot.edge(&i.to_string(), &j.to_string(), &[]);
+            }
+        }
+        dot.end();
+    }
+    dot.finish()
+}
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Playground";
+
+    type Input = Vec<(i64, i64, i64)>;
+    type PartOne = BigUint;
+    type PartTwo = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<BigUint> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer> {
+        part_two(input)
+    }
+
+    fn dot(input: &Self::Input) -> Result<Option<String>> {
+        Ok(Some(dot(input, connections(input))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_example;
+
+    #[test]
+    fn example() -> Result<()> {
+        let input = parse_input(&read_example(YEAR, 8)?)?;
+        assert_eq!(solve(&input, 10)?, 40);
+        assert_eq!(part_two(&input)?, Answer::from(25272));
+
+        // A single box is never joined to anything
+        assert_eq!(part_two(&[(1, 2, 3)])?, Answer::Unsolved);
+        Ok(())
+    }
+
+    #[test]
+    fn draw() -> Result<()> {
+        let input = parse_input(&read_example(YEAR, 8)?)?;
+        let dot = dot(&input, 10);
+        assert!(
+            dot.contains("// connections: 10, circuits: 4, boxes alone: 7")
+        );
+        // The largest circuits come first
+        let labels: Vec<&str> = (dot.lines())
+            .filter_map(|line| {
+                line.trim().strip_prefix("label=\"circuit of ")
+            })
+            .collect();
+        assert_eq!(labels, ["5\"", "4\"", "2\"", "2\""]);
+        assert_eq!(dot.matches(" -- ").count(), 10);
+        assert!(dot.contains(r#""0" [label="162,817,812"]"#));
+
+        // Part one's connections are capped at the pairs there are
+        assert_eq!(connections(&input), 190);
+        let dot = Day08::dot(&input)?.unwrap();
+        assert!(dot.contains("// connections: 190, circuits: 1,"));
+        Ok(())
+    }
+}
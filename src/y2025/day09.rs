@@ -1,17 +1,23 @@
-fn parse_input(input: &str) -> Vec<(i64, i64)> {
+use super::YEAR;
+use crate::error::parse;
+use crate::{ParseError, Result, Solver};
+
+const DAY: u8 = 9;
+
+fn parse_input(input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
     input
         .lines()
         .filter(|line| !line.is_empty())
         .map(|line| {
-            let nums: Vec<i64> =
-                line.split(',').map(|n| n.parse().unwrap()).collect();
-            (nums[0], nums[1])
+            let (x, y) = line.split_once(',').ok_or_else(|| {
+                ParseError::locate(DAY, input, line, "expected `x,y`")
+            })?;
+            Ok((parse(DAY, input, x)?, parse(DAY, input, y)?))
         })
         .collect()
 }
 
-pub fn part_one(input: &str) -> i64 {
-    let tiles = parse_input(input);
+pub fn part_one(tiles: &[(i64, i64)]) -> Result<i64> {
     let n = tiles.len();
     let mut max_area = 0;
 
@@ -27,7 +33,7 @@ pub fn part_one(input: &str) -> i64 {
         }
     }
 
-    max_area
+    Ok(max_area)
 }
 
 use std::collections::HashSet;
@@ -155,8 +161,7 @@ fn is_rect_fully_inside(
     true
 }
 
-pub fn part_two(input: &str) -> i64 {
-    let red_tiles = parse_input(input);
+pub fn part_two(red_tiles: &[(i64, i64)]) -> Result<i64> {
     let n = red_tiles.len();
 
     let mut max_area = 0;
@@ -172,14 +177,38 @@ pub fn part_two(input: &str) -> i64 {
             let ly = y1.min(y2);
             let ry = y1.max(y2);
 
-            if is_rect_fully_inside(&red_tiles, lx, rx, ly, ry) {
+            if is_rect_fully_inside(red_tiles, lx, rx, ly, ry) {
                 let area = (rx - lx + 1) * (ry - ly + 1);
                 max_area = max_area.max(area);
             }
         }
     }
 
-    max_area
+    Ok(max_area)
+}
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Movie Theater";
+
+    type Input = Vec<(i64, i64)>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<i64> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<i64> {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -188,9 +217,10 @@ mod tests {
     use crate::read_example;
 
     #[test]
-    fn example() {
-        let input = read_example(9);
-        assert_eq!(part_one(&input), 50);
-        assert_eq!(part_two(&input), 24);
+    fn example() -> Result<()> {
+        let input = parse_input(&read_example(YEAR, 9)?)?;
+        assert_eq!(part_one(&input)?, 50);
+        assert_eq!(part_two(&input)?, 24);
+        Ok(())
     }
 }
@@ -1,46 +1,74 @@
-fn parse_line(line: &str) -> (Vec<bool>, Vec<Vec<usize>>, Vec<i64>) {
+use super::YEAR;
+use crate::error::{expect_chars, parse};
+use crate::{check_cancelled, ParseError, Result, Solver};
+
+const DAY: u8 = 10;
+
+type Machine = (Vec<bool>, Vec<Vec<usize>>, Vec<i64>);
+
+// Returns the text between `open` and the following `close` in `line`
+fn delimited<'a>(
+    input: &str,
+    line: &'a str,
+    open: char,
+    close: char,
+) -> Result<(&'a str, usize, usize), ParseError> {
+    let error = |message| ParseError::locate(DAY, input, line, message);
+    let start = line
+        .find(open)
+        .ok_or_else(|| error(format!("expected `{open}`")))?;
+    let end = line[start..]
+        .find(close)
+        .map(|i| start + i)
+        .ok_or_else(|| error(format!("expected `{close}`")))?;
+    Ok((&line[start + 1..end], start, end))
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Machine, ParseError> {
     // Parse indicator lights [.##.]
-    let bracket_start = line.find('[').unwrap();
-    let bracket_end = line.find(']').unwrap();
-    let lights: Vec<bool> = line[bracket_start + 1..bracket_end]
-        .chars()
-        .map(|c| c == '#')
-        .collect();
-
-    // Parse button wiring schematics (x,y,z)
-    let mut buttons: Vec<Vec<usize>> = Vec::new();
-    let mut i = bracket_end + 1;
-    let chars: Vec<char> = line.chars().collect();
-
-    while i < chars.len() {
-        if chars[i] == '(' {
-            let paren_end = line[i..].find(')').unwrap() + i;
-            let content = &line[i + 1..paren_end];
-            let indices: Vec<usize> = content
-                .split(',')
-                .map(|s| s.trim().parse().unwrap())
-                .collect();
-            buttons.push(indices);
-            i = paren_end + 1;
-        } else if chars[i] == '{' {
-            break;
-        } else {
-            i += 1;
-        }
-    }
+    let (lights, _, bracket_end) = delimited(input, line, '[', ']')?;
+    expect_chars(DAY, input, lights, ".#")?;
+    let lights: Vec<bool> = lights.chars().map(|c| c == '#').collect();
 
     // Parse joltage requirements {x,y,z}
-    let brace_start = line.find('{').unwrap();
-    let brace_end = line.find('}').unwrap();
-    let joltage: Vec<i64> = line[brace_start + 1..brace_end]
+    let (joltage, brace_start, _) = delimited(input, line, '{', '}')?;
+    let joltage: Vec<i64> = joltage
         .split(',')
-        .map(|s| s.trim().parse().unwrap())
-        .collect();
+        .map(|s| parse(DAY, input, s))
+        .collect::<Result<_, _>>()?;
+
+    // Parse button wiring schematics (x,y,z) between the two
+    let mut buttons: Vec<Vec<usize>> = Vec::new();
+    for schematic in line[bracket_end + 1..brace_start].split_whitespace() {
+        let Some(content) = schematic
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+        else {
+            let message = "expected a button like `(0,2)`";
+            return Err(ParseError::locate(DAY, input, schematic, message));
+        };
+        let indices: Vec<usize> = content
+            .split(',')
+            .map(|s| parse(DAY, input, s))
+            .collect::<Result<_, _>>()?;
+        buttons.push(indices);
+    }
+
+    Ok((lights, buttons, joltage))
+}
 
-    (lights, buttons, joltage)
+fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_line(input, line))
+        .collect()
 }
 
-fn solve_machine(target: &[bool], buttons: &[Vec<usize>]) -> Option<usize> {
+fn solve_machine(
+    target: &[bool],
+    buttons: &[Vec<usize>],
+) -> Result<Option<usize>> {
     let n_lights = target.len();
     let n_buttons = buttons.len();
 
@@ -50,6 +78,7 @@ fn solve_machine(target: &[bool], buttons: &[Vec<usize>]) -> Option<usize> {
         let mut min_presses = usize::MAX;
 
         for mask in 0..(1u32 << n_buttons) {
+            check_cancelled()?;
             let mut state = vec![false; n_lights];
             let mut presses = 0;
 
@@ -70,9 +99,9 @@ fn solve_machine(target: &[bool], buttons: &[Vec<usize>]) -> Option<usize> {
         }
 
         if min_presses == usize::MAX {
-            None
+            Ok(None)
         } else {
-            Some(min_presses)
+            Ok(Some(min_presses))
         }
     } else {
         // Use Gaussian elimination for larger inputs
@@ -80,7 +109,10 @@ fn solve_machine(target: &[bool], buttons: &[Vec<usize>]) -> Option<usize> {
     }
 }
 
-fn solve_gaussian(target: &[bool], buttons: &[Vec<usize>]) -> Option<usize> {
+fn solve_gaussian(
+    target: &[bool],
+    buttons: &[Vec<usize>],
+) -> Result<Option<usize>> {
     let n_lights = target.len();
     let n_buttons = buttons.len();
 
@@ -138,7 +170,7 @@ fn solve_gaussian(target: &[bool], buttons: &[Vec<usize>]) -> Option<usize> {
     // Check for inconsistency
     for row in matrix.iter().take(n_lights).skip(pivot_row) {
         if row[n_buttons] {
-            return None; // No solution
+            return Ok(None); // No solution
         }
     }
 
@@ -151,6 +183,7 @@ fn solve_gaussian(target: &[bool], buttons: &[Vec<usize>]) -> Option<usize> {
     let mut min_presses = usize::MAX;
 
     for free_mask in 0..(1u32 << n_free) {
+        check_cancelled()?;
         let mut solution = vec![false; n_buttons];
 
         // Set free variables
@@ -173,16 +206,14 @@ fn solve_gaussian(target: &[bool], buttons: &[Vec<usize>]) -> Option<usize> {
         min_presses = min_presses.min(presses);
     }
 
-    Some(min_presses)
+    Ok(Some(min_presses))
 }
 
-pub fn part_one(input: &str) -> usize {
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let (target, buttons, _) = parse_line(line);
-            solve_machine(&target, &buttons).unwrap_or(0)
+pub fn part_one(machines: &[Machine]) -> Result<usize> {
+    machines
+        .iter()
+        .map(|(target, buttons, _)| {
+            Ok(solve_machine(target, buttons)?.unwrap_or(0))
         })
         .sum()
 }
@@ -191,7 +222,7 @@ pub fn part_one(input: &str) -> usize {
 // We need to find non-negative integers x_i such that:
 // sum(x_i * a_ij) = b_j for each counter j
 // and minimize sum(x_i)
-fn solve_joltage(target: &[i64], buttons: &[Vec<usize>]) -> i64 {
+fn solve_joltage(target: &[i64], buttons: &[Vec<usize>]) -> Result<i64> {
     let n_counters = target.len();
     let n_buttons = buttons.len();
 
@@ -219,31 +250,31 @@ fn solve_ilp(
     target: &[i64],
     n_buttons: usize,
     max_total: usize,
-) -> i64 {
+) -> Result<i64> {
     // Try to find solution using Gaussian elimination on integers
-    if let Some(result) = solve_nonneg_integer(a, target, n_buttons) {
-        return result;
+    if let Some(result) = solve_nonneg_integer(a, target, n_buttons)? {
+        return Ok(result);
     }
 
     // Fallback: brute force for small cases
     if n_buttons <= 10 && max_total <= 20 {
         for total in 0..=max_total {
             if let Some(presses) =
-                find_combination(a, target, n_buttons, total)
+                find_combination(a, target, n_buttons, total)?
             {
-                return presses as i64;
+                return Ok(presses as i64);
             }
         }
     }
 
-    0
+    Ok(0)
 }
 
 fn solve_nonneg_integer(
     a: &[Vec<i64>],
     target: &[i64],
     n_buttons: usize,
-) -> Option<i64> {
+) -> Result<Option<i64>> {
     let n_counters = target.len();
 
     // Build augmented matrix [A | b]
@@ -297,7 +328,7 @@ fn solve_nonneg_integer(
     // Check for inconsistency
     for row in matrix.iter().take(n_counters).skip(pivot_row) {
         if row[n_buttons] != 0 {
-            return None;
+            return Ok(None);
         }
     }
 
@@ -320,12 +351,12 @@ fn solve_nonneg_integer(
         max_search,
         &mut min_presses,
         0, // current_sum starts at 0
-    );
+    )?;
 
     if min_presses == i64::MAX {
-        None
+        Ok(None)
     } else {
-        Some(min_presses)
+        Ok(Some(min_presses))
     }
 }
 
@@ -340,10 +371,13 @@ fn search_solution(
     max_val: i64,
     min_presses: &mut i64,
     current_sum: i64,
-) {
+) -> Result<()> {
+    // Give up if the run was cancelled, e.g. by a timeout
+    check_cancelled()?;
+
     // Pruning: if current sum of free variables already >= best solution, skip
     if current_sum >= *min_presses {
-        return;
+        return Ok(());
     }
 
     if free_idx == free_cols.len() {
@@ -358,25 +392,25 @@ fn search_solution(
             }
 
             if matrix[row][col] == 0 || sum % matrix[row][col] != 0 {
-                return;
+                return Ok(());
             }
 
             let val = sum / matrix[row][col];
             if val < 0 {
-                return; // Early exit if negative
+                return Ok(()); // Early exit if negative
             }
             sol[col] = val;
             pivot_sum += val;
             
             // Early pruning during back-substitution
             if current_sum + pivot_sum >= *min_presses {
-                return;
+                return Ok(());
             }
         }
 
         let total = current_sum + pivot_sum;
         *min_presses = (*min_presses).min(total);
-        return;
+        return Ok(());
     }
 
     let col = free_cols[free_idx];
@@ -385,7 +419,7 @@ fn search_solution(
     let effective_max = if remaining_budget >= 0 {
         max_val.min(remaining_budget)
     } else {
-        return;
+        return Ok(());
     };
 
     for val in 0..=effective_max {
@@ -400,9 +434,10 @@ fn search_solution(
             max_val,
             min_presses,
             current_sum + val,
-        );
+        )?;
     }
     solution[col] = 0;
+    Ok(())
 }
 
 fn gcd(a: i64, b: i64) -> i64 {
@@ -418,7 +453,7 @@ fn find_combination(
     target: &[i64],
     n_buttons: usize,
     total: usize,
-) -> Option<usize> {
+) -> Result<Option<usize>> {
     // Try to find combination of exactly `total` presses
     let n_counters = target.len();
 
@@ -430,15 +465,16 @@ fn find_combination(
         remaining: usize,
         n_buttons: usize,
         n_counters: usize,
-    ) -> bool {
+    ) -> Result<bool> {
+        check_cancelled()?;
         if button == n_buttons {
-            return remaining == 0 && state == target;
+            return Ok(remaining == 0 && state == target);
         }
 
         // Pruning: check if any counter is already exceeded
         for j in 0..n_counters {
             if state[j] > target[j] {
-                return false;
+                return Ok(false);
             }
         }
 
@@ -456,8 +492,8 @@ fn find_combination(
                 remaining - presses,
                 n_buttons,
                 n_counters,
-            ) {
-                return true;
+            )? {
+                return Ok(true);
             }
 
             for j in 0..n_counters {
@@ -465,37 +501,58 @@ fn find_combination(
             }
         }
 
-        false
+        Ok(false)
     }
 
     let mut state = vec![0i64; n_counters];
-    if backtrack(a, target, &mut state, 0, total, n_buttons, n_counters) {
-        Some(total)
+    if backtrack(a, target, &mut state, 0, total, n_buttons, n_counters)? {
+        Ok(Some(total))
     } else {
-        None
+        Ok(None)
     }
 }
 
-pub fn part_two(input: &str) -> i64 {
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let (_, buttons, joltage) = parse_line(line);
-            solve_joltage(&joltage, &buttons)
-        })
+pub fn part_two(machines: &[Machine]) -> Result<i64> {
+    machines
+        .iter()
+        .map(|(_, buttons, joltage)| solve_joltage(joltage, buttons))
         .sum()
 }
 
+pub struct Day10;
+
+impl Solver for Day10 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Factory";
+
+    type Input = Vec<Machine>;
+    type PartOne = usize;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<i64> {
+        part_two(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::read_example;
 
     #[test]
-    fn example() {
-        let input = read_example(10);
-        assert_eq!(part_one(&input), 7);
-        assert_eq!(part_two(&input), 33);
+    fn example() -> Result<()> {
+        let input = parse_input(&read_example(YEAR, 10)?)?;
+        assert_eq!(part_one(&input)?, 7);
+        assert_eq!(part_two(&input)?, 33);
+        Ok(())
     }
 }
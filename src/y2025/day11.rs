@@ -0,0 +1,303 @@
+use std::collections::HashSet;
+
+use super::YEAR;
+use crate::{
+    BigUint, Cycle, Dot, Error, Graph, NodeId, ParseError, Result, Route,
+    RouteError, RoutePaths, Solver,
+};
+
+const DAY: u8 = 11;
+
+/// Paths between devices, named as in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    pub from: String,
+    pub to: String,
+    /// Devices every path must pass through.
+    pub via: Vec<String>,
+    /// Whether `via` must be passed through in the order given.
+    pub ordered: bool,
+    /// Devices no path may pass through.
+    pub avoid: Vec<String>,
+}
+
+impl Query {
+    pub fn new(from: &str, to: &str) -> Self {
+        Self {
+            from: from.to_string(),
+            to: to.to_string(),
+            via: Vec::new(),
+            ordered: false,
+            avoid: Vec::new(),
+        }
+    }
+
+    fn route(&self, graph: &Graph) -> Result<Route> {
+        let nodes = |names: &[String]| {
+            (names.iter())
+                .map(|name| node(graph, name))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(Route {
+            from: node(graph, &self.from)?,
+            to: node(graph, &self.to)?,
+            via: nodes(&self.via)?,
+            ordered: self.ordered,
+            avoid: nodes(&self.avoid)?,
+        })
+    }
+}
+
+fn parse_input(input: &str) -> Result<Graph, ParseError> {
+    Graph::parse(DAY, input)
+}
+
+// A node the puzzle names, or an error if the input lacks it
+fn node(graph: &Graph, name: &str) -> Result<NodeId> {
+    graph.id(name).ok_or_else(|| Error::Unsolvable {
+        day: DAY,
+        message: format!("no device named `{name}`"),
+    })
+}
+
+// Too many devices to track, or a loop on the way, each trip around which
+// makes another path
+fn route_error(graph: &Graph, err: RouteError) -> Error {
+    let message = match err {
+        RouteError::TooManyWaypoints(count) => format!(
+            "{count} devices are too many to list paths through in any \
+             order"
+        ),
+        RouteError::Infinite(cycle) => format!(
+            "infinitely many paths, around {}",
+            graph.format_cycle(&cycle)
+        ),
+    };
+    Error::Unsolvable { day: DAY, message }
+}
+
+/// The paths `query` describes, to count, list or sample.
+pub fn paths<'a>(graph: &'a Graph, query: &Query) -> Result<RoutePaths<'a>> {
+    let route = query.route(graph)?;
+    graph
+        .route_paths(&route)
+        .map_err(|err| route_error(graph, err))
+}
+
+/// The number of paths `query` describes.
+pub fn count_paths(graph: &Graph, query: &Query) -> Result<BigUint> {
+    let route = query.route(graph)?;
+    graph
+        .count_route(&route)
+        .map_err(|err| route_error(graph, err))
+}
+
+/// The graph as Graphviz DOT, each device labelled with how many of the
+/// paths `query` describes pass through it. The ends of the paths are
+/// green and red, waypoints gold and avoided devices grey; devices off
+/// every path are dashed.
+///
+/// When the paths cannot be counted the graph is drawn without counts,
+/// and a loop that makes them infinite is drawn in red.
+pub fn dot(graph: &Graph, query: &Query) -> Result<String> {
+    let route = query.route(graph)?;
+    let (visits, problem) = match graph.route_paths(&route) {
+        Ok(paths) => (Some(paths.visits()), None),
+        Err(err) => (None, Some(err)),
+    };
+    let mut looping = HashSet::new();
+    if let Some(RouteError::Infinite(Cycle(cycle))) = &problem {
+        for (i, &node) in cycle.iter().enumerate() {
+            looping.insert((node, cycle[(i + 1) % cycle.len()]));
+        }
+    }
+
+    let mut dot = Dot::digraph("day11");
+    if let Some(err) = problem {
+        dot.comment(&route_error(graph, err).to_string());
+    }
+    dot.set("rankdir", "LR");
+    dot.defaults("node", &[("shape", "box"), ("style", "rounded")]);
+    for node in 0..graph.len() {
+        let name = graph.name(node);
+        let visits = visits.as_ref().map(|visits| &visits[node]);
+        let colour = if node == route.from {
+            "palegreen"
+        } else if node == route.to {
+            "lightcoral"
+        } else if route.via.contains(&node) {
+            "gold"
+        } else if route.avoid.contains(&node) {
+            "lightgrey"
+        } else {
+            ""
+        };
+        let label = match visits {
+            Some(visits) => format!("{name}\n{visits}"),
+            None => name.to_string(),
+        };
+        let mut attrs = vec![("label", label.as_str())];
+        if !colour.is_empty() {
+            attrs
+                .extend([("style", "rounded,filled"), ("fillcolor", colour)]);
+        } else if visits.is_some_and(BigUint::is_zero) {
+            attrs.push(("style", "rounded,dashed"));
+        }
+        dot.node(name, &attrs);
+    }
+    for (from, to) in graph.edges() {
+        let attrs: &[_] = if looping.contains(&(from, to)) {
+            &[("color", "red")]
+        } else {
+            &[]
+        };
+        dot.edge(graph.name(from), graph.name(to), attrs);
+    }
+    Ok(dot.finish())
+}
+
+// The route part two counts, or part one's for inputs without it such as
+// the first example
+fn dot_query(graph: &Graph) -> Query {
+    if graph.id("svr").is_some() {
+        part_two_query()
+    } else {
+        Query::new("you", "out")
+    }
+}
+
+fn part_two_query() -> Query {
+    Query {
+        via: vec!["dac".to_string(), "fft".to_string()],
+        ..Query::new("svr", "out")
+    }
+}
+
+pub fn part_one(graph: &Graph) -> Result<BigUint> {
+    count_paths(graph, &Query::new("you", "out"))
+}
+
+pub fn part_two(graph: &Graph) -> Result<BigUint> {
+    count_paths(graph, &part_two_query())
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Reactor";
+
+    type Input = Graph;
+    type PartOne = BigUint;
+    type PartTwo = BigUint;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<BigUint> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<BigUint> {
+        part_two(input)
+    }
+
+    fn dot(input: &Self::Input) -> Result<Option<String>> {
+        dot(input, &dot_query(input)).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_example_for, Part};
+
+    #[test]
+    fn example() -> Result<()> {
+        let input = parse_input(&read_example_for(YEAR, DAY, Part::One)?)?;
+        assert_eq!(part_one(&input)?, 5);
+        let input = parse_input(&read_example_for(YEAR, DAY, Part::Two)?)?;
+        assert_eq!(part_two(&input)?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn queries() -> Result<()> {
+        let input = parse_input(&read_example_for(YEAR, DAY, Part::Two)?)?;
+        let names = |names: &[&str]| -> Vec<String> {
+            names.iter().map(|name| name.to_string()).collect()
+        };
+        let mut query = Query {
+            via: names(&["dac", "fft", "hhh"]),
+            ..Query::new("svr", "out")
+        };
+        assert_eq!(count_paths(&input, &query)?, 1);
+        query.ordered = true;
+        assert_eq!(count_paths(&input, &query)?, 0);
+        query.via = names(&["fft", "dac"]);
+        assert_eq!(count_paths(&input, &query)?, 2);
+        query.avoid = names(&["ggg"]);
+        assert_eq!(count_paths(&input, &query)?, 1);
+
+        let listed: Vec<String> = paths(&input, &query)?
+            .iter()
+            .map(|path| input.format_path(&path))
+            .collect();
+        assert_eq!(
+            listed,
+            ["svr -> aaa -> fft -> ccc -> eee -> dac -> fff -> hhh -> out"]
+        );
+
+        query.avoid = names(&["zzz"]);
+        let err = count_paths(&input, &query).unwrap_err();
+        assert_eq!(err.to_string(), "day 11: no device named `zzz`");
+        assert!(matches!(err, Error::Unsolvable { day: DAY, .. }));
+        Ok(())
+    }
+
+    #[test]
+    fn draw() -> Result<()> {
+        let input = parse_input(&read_example_for(YEAR, DAY, Part::Two)?)?;
+        let dot = Day11::dot(&input)?.unwrap();
+        assert!(dot.starts_with("digraph \"day11\" {\n"));
+        for line in [
+            r#""svr" [label="svr\n2", style="rounded,filled""#,
+            r#"[label="dac\n2", style="rounded,filled", fillcolor="gold"]"#,
+            r#""bbb" [label="bbb\n0", style="rounded,dashed"]"#,
+            r#""ccc" [label="ccc\n2"]"#,
+            r#""fff" -> "ggg""#,
+        ] {
+            assert!(dot.contains(line), "no `{line}` in\n{dot}");
+        }
+
+        // Infinitely many paths: no counts, and the loop in red
+        let input = parse_input("you: a\na: b out\nb: a\n")?;
+        let dot = Day11::dot(&input)?.unwrap();
+        for line in [
+            "// day 11: infinitely many paths, around a -> b -> a",
+            r#""b" [label="b"]"#,
+            r#""a" -> "b" [color="red"]"#,
+            "\"a\" -> \"out\"\n",
+        ] {
+            assert!(dot.contains(line), "no `{line}` in\n{dot}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn loops() -> Result<()> {
+        // A loop off every path to `out` is harmless
+        let input = parse_input("you: a b\na: out\nb: c\nc: b\n")?;
+        assert_eq!(part_one(&input)?, 1);
+
+        let input = parse_input("you: a\na: b out\nb: a\n")?;
+        let err = part_one(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 11: infinitely many paths, around a -> b -> a"
+        );
+        Ok(())
+    }
+}
@@ -1,9 +1,17 @@
 use std::collections::HashSet;
 
+use super::YEAR;
+use crate::error::{expect_chars, parse};
+use crate::{check_cancelled, Answer, Grid, ParseError, Pos, Result, Solver};
+
+const DAY: u8 = 12;
+
 type Shape = Vec<(i32, i32)>; // List of (row, col) offsets
 type Region = (usize, usize, Vec<usize>); // (width, height, counts)
 
-fn parse_input(input: &str) -> (Vec<Shape>, Vec<Region>) {
+type Farm = (Vec<Shape>, Vec<Region>);
+
+fn parse_input(input: &str) -> Result<Farm, ParseError> {
     let parts: Vec<&str> = input.split("\n\n").collect();
     let mut shapes: Vec<Shape> = Vec::new();
     let mut regions: Vec<(usize, usize, Vec<usize>)> = Vec::new();
@@ -22,6 +30,7 @@ fn parse_input(input: &str) -> (Vec<Shape>, Vec<Region>) {
         {
             let mut shape = Vec::new();
             for (row, line) in lines[1..].iter().enumerate() {
+                expect_chars(DAY, input, line, "#.")?;
                 for (col, ch) in line.chars().enumerate() {
                     if ch == '#' {
                         shape.push((row as i32, col as i32));
@@ -33,21 +42,20 @@ fn parse_input(input: &str) -> (Vec<Shape>, Vec<Region>) {
             // This part contains regions
             for line in lines {
                 if line.contains('x') && line.contains(':') {
-                    let parts: Vec<&str> = line.split(':').collect();
-                    let size_part = parts[0].trim();
-                    let counts_part = parts[1].trim();
-
-                    let dimensions: Vec<usize> = size_part
-                        .split('x')
-                        .map(|s| s.parse().unwrap())
-                        .collect();
-                    let width = dimensions[0];
-                    let height = dimensions[1];
+                    let (size_part, counts_part) =
+                        line.split_once(':').unwrap_or_default();
+                    let (width, height) =
+                        size_part.split_once('x').ok_or_else(|| {
+                            let message = "expected `WxH`";
+                            ParseError::locate(DAY, input, size_part, message)
+                        })?;
+                    let width = parse(DAY, input, width)?;
+                    let height = parse(DAY, input, height)?;
 
                     let counts: Vec<usize> = counts_part
                         .split_whitespace()
-                        .map(|s| s.parse().unwrap())
-                        .collect();
+                        .map(|s| parse(DAY, input, s))
+                        .collect::<Result<_, _>>()?;
 
                     regions.push((width, height, counts));
                 }
@@ -55,7 +63,7 @@ fn parse_input(input: &str) -> (Vec<Shape>, Vec<Region>) {
         }
     }
 
-    (shapes, regions)
+    Ok((shapes, regions))
 }
 
 // Generate all rotations and reflections of a shape
@@ -85,32 +93,39 @@ fn normalize(shape: &Shape) -> Shape {
     result
 }
 
+// The grid cell under offset (dr, dc) of a shape placed at (row, col)
+fn cell(
+    grid: &Grid<bool>,
+    row: i32,
+    col: i32,
+    (dr, dc): (i32, i32),
+) -> Option<Pos> {
+    grid.checked_pos((row + dr) as isize, (col + dc) as isize)
+}
+
 // Check if a shape can be placed at (row, col) on the grid
-fn can_place(grid: &[Vec<bool>], shape: &Shape, row: i32, col: i32, height: usize, width: usize) -> bool {
-    for &(dr, dc) in shape {
-        let r = row + dr;
-        let c = col + dc;
-        if r < 0 || c < 0 || r >= height as i32 || c >= width as i32 {
-            return false;
-        }
-        if grid[r as usize][c as usize] {
-            return false; // Cell is already occupied
-        }
-    }
-    true
+fn can_place(grid: &Grid<bool>, shape: &Shape, row: i32, col: i32) -> bool {
+    // Every cell must be on the grid and not already occupied
+    shape.iter().all(|&offset| {
+        cell(grid, row, col, offset).is_some_and(|pos| !grid[pos])
+    })
 }
 
 // Place a shape on the grid
-fn place(grid: &mut [Vec<bool>], shape: &Shape, row: i32, col: i32) {
-    for &(dr, dc) in shape {
-        grid[(row + dr) as usize][(col + dc) as usize] = true;
+fn place(grid: &mut Grid<bool>, shape: &Shape, row: i32, col: i32) {
+    for &offset in shape {
+        if let Some(pos) = cell(grid, row, col, offset) {
+            grid[pos] = true;
+        }
     }
 }
 
 // Remove a shape from the grid
-fn unplace(grid: &mut [Vec<bool>], shape: &Shape, row: i32, col: i32) {
-    for &(dr, dc) in shape {
-        grid[(row + dr) as usize][(col + dc) as usize] = false;
+fn unplace(grid: &mut Grid<bool>, shape: &Shape, row: i32, col: i32) {
+    for &offset in shape {
+        if let Some(pos) = cell(grid, row, col, offset) {
+            grid[pos] = false;
+        }
     }
 }
 
@@ -118,31 +133,22 @@ fn unplace(grid: &mut [Vec<bool>], shape: &Shape, row: i32, col: i32) {
 // Key insight: when placing a piece, we MUST cover the first empty cell
 // Unless no piece can cover it, in which case we mark it as permanently empty
 fn solve(
-    grid: &mut Vec<Vec<bool>>,
-    width: usize,
-    height: usize,
+    grid: &mut Grid<bool>,
     pieces: &[Vec<Shape>], // All orientations for each piece
     remaining_pieces: &mut Vec<usize>, // Indices of pieces not yet placed
     empty_budget: usize, // How many cells can remain empty
-) -> bool {
+) -> Result<bool> {
     if remaining_pieces.is_empty() {
-        return true; // All pieces placed
+        return Ok(true); // All pieces placed
     }
 
-    // Find first empty cell
-    let mut first_empty = None;
-    'outer: for (r, row) in grid.iter().enumerate().take(height) {
-        for (c, &cell) in row.iter().enumerate().take(width) {
-            if !cell {
-                first_empty = Some((r, c));
-                break 'outer;
-            }
-        }
-    }
+    // Give up if the run was cancelled, e.g. by a timeout
+    check_cancelled()?;
 
-    let (start_row, start_col) = match first_empty {
+    // Find first empty cell
+    let (start_row, start_col) = match grid.find(&false) {
         Some(pos) => pos,
-        None => return remaining_pieces.is_empty(),
+        None => return Ok(remaining_pieces.is_empty()),
     };
 
     // Try each remaining piece to cover the first empty cell
@@ -157,13 +163,13 @@ fn solve(
                 let row = start_row as i32 - dr;
                 let col = start_col as i32 - dc;
 
-                if can_place(grid, orientation, row, col, height, width) {
+                if can_place(grid, orientation, row, col) {
                     any_placed = true;
                     place(grid, orientation, row, col);
                     remaining_pieces.remove(i);
 
-                    if solve(grid, width, height, pieces, remaining_pieces, empty_budget) {
-                        return true;
+                    if solve(grid, pieces, remaining_pieces, empty_budget)? {
+                        return Ok(true);
                     }
 
                     remaining_pieces.insert(i, piece_idx);
@@ -175,17 +181,17 @@ fn solve(
 
     // If no piece can cover this cell and we have empty budget, skip this cell
     if !any_placed && empty_budget > 0 {
-        grid[start_row][start_col] = true; // Mark as "used" (will be empty in final)
-        if solve(grid, width, height, pieces, remaining_pieces, empty_budget - 1) {
-            return true;
+        grid[(start_row, start_col)] = true; // Mark as "used" (will be empty in final)
+        if solve(grid, pieces, remaining_pieces, empty_budget - 1)? {
+            return Ok(true);
         }
-        grid[start_row][start_col] = false;
+        grid[(start_row, start_col)] = false;
     }
 
-    false
+    Ok(false)
 }
 
-fn can_fit_precomputed(all_orientations: &[Vec<Shape>], width: usize, height: usize, counts: &[usize]) -> bool {
+fn can_fit_precomputed(all_orientations: &[Vec<Shape>], width: usize, height: usize, counts: &[usize]) -> Result<bool> {
     // Collect all pieces to place with their orientations (using pre-computed orientations)
     let mut pieces: Vec<Vec<Shape>> = Vec::new();
     let mut piece_sizes: Vec<usize> = Vec::new();
@@ -204,7 +210,7 @@ fn can_fit_precomputed(all_orientations: &[Vec<Shape>], width: usize, height: us
     // Check if total cells exceeds grid
     let total_cells: usize = piece_sizes.iter().sum();
     if total_cells > width * height {
-        return false;
+        return Ok(false);
     }
 
     // Sort pieces by size (largest first for better pruning)
@@ -215,28 +221,55 @@ fn can_fit_precomputed(all_orientations: &[Vec<Shape>], width: usize, height: us
     // Reorder pieces
     let sorted_pieces: Vec<Vec<Shape>> = indices.iter().map(|&i| pieces[i].clone()).collect();
 
-    let mut grid = vec![vec![false; width]; height];
+    let mut grid = Grid::new(width, height, false);
     let mut remaining: Vec<usize> = (0..sorted_pieces.len()).collect();
     let empty_budget = width * height - total_cells;
-    solve(&mut grid, width, height, &sorted_pieces, &mut remaining, empty_budget)
+    solve(&mut grid, &sorted_pieces, &mut remaining, empty_budget)
 }
 
-pub fn part_one(input: &str) -> usize {
-    let (shapes, regions) = parse_input(input);
+pub fn part_one((shapes, regions): &Farm) -> Result<usize> {
     
     // Pre-compute all orientations for all shapes
     let all_orientations: Vec<Vec<Shape>> = shapes.iter()
         .map(get_orientations)
         .collect();
     
-    regions
-        .iter()
-        .filter(|(width, height, counts)| can_fit_precomputed(&all_orientations, *width, *height, counts))
-        .count()
+    let mut count = 0;
+    for (width, height, counts) in regions {
+        if can_fit_precomputed(&all_orientations, *width, *height, counts)? {
+            count += 1;
+        }
+    }
+    Ok(count)
 }
 
-pub fn part_two(_input: &str) -> usize {
-    0
+// The last day has no second puzzle
+pub fn part_two(_farm: &Farm) -> Result<Answer> {
+    Ok(Answer::Unsolved)
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const YEAR: u16 = YEAR;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Christmas Tree Farm";
+
+    type Input = Farm;
+    type PartOne = usize;
+    type PartTwo = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer> {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -245,8 +278,9 @@ mod tests {
     use crate::read_example;
 
     #[test]
-    fn example() {
-        let input = read_example(12);
-        assert_eq!(part_one(&input), 2);
+    fn example() -> Result<()> {
+        let input = parse_input(&read_example(YEAR, 12)?)?;
+        assert_eq!(part_one(&input)?, 2);
+        Ok(())
     }
 }